@@ -1,20 +1,14 @@
-use rusty_bybit::BybitClient;
+use rusty_bybit::{BybitClient, TradingStopRequest};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Bybit Account Management Examples\n");
 
-    let api_key = std::env::var("BYBIT_API_KEY").unwrap_or_else(|_| {
-        eprintln!("Warning: BYBIT_API_KEY not set, using placeholder");
-        "your_api_key".to_string()
-    });
-
-    let api_secret = std::env::var("BYBIT_API_SECRET").unwrap_or_else(|_| {
-        eprintln!("Warning: BYBIT_API_SECRET not set, using placeholder");
-        "your_api_secret".to_string()
-    });
+    if std::env::var("BYBIT_API_KEY").is_err() || std::env::var("BYBIT_API_SECRET").is_err() {
+        eprintln!("Warning: BYBIT_API_KEY/BYBIT_API_SECRET not set, private endpoints will fail");
+    }
 
-    let client = BybitClient::testnet().with_credentials(api_key, api_secret);
+    let client = BybitClient::from_env()?;
 
     println!("1. Getting wallet balance...");
     match client.get_wallet_balance(None).await {
@@ -45,8 +39,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Err(e) => println!("   Error getting wallet balance: {}", e),
     }
 
-    println!("\n2. Getting positions for linear market...");
-    match client.get_position("linear", None).await {
+    println!("\n2. Getting USDT-settled linear positions...");
+    match client
+        .get_position("linear", None, Some("USDT"), None, None)
+        .await
+    {
         Ok(positions) => {
             println!("   Total positions: {}", positions.list.len());
             for position in positions.list.iter() {
@@ -63,7 +60,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     println!("\n3. Getting specific position for BTCUSDT...");
-    match client.get_position("linear", Some("BTCUSDT")).await {
+    match client
+        .get_position("linear", Some("BTCUSDT"), None, None, None)
+        .await
+    {
         Ok(positions) => {
             if !positions.list.is_empty() {
                 let position = &positions.list[0];
@@ -85,78 +85,80 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Err(e) => println!("   Error setting leverage: {}", e),
     }
 
-    println!("\n5. Getting execution list...");
-    match client.get_execution_list("linear", None).await {
+    println!("\n5. Setting stop loss on the BTCUSDT position...");
+    let trading_stop = TradingStopRequest::builder()
+        .category("linear")
+        .symbol("BTCUSDT")
+        .position_idx(0)
+        .stop_loss("25000")
+        .sl_trigger_by("MarkPrice")
+        .tpsl_mode("Full")
+        .build()?;
+    match client.set_trading_stop(&trading_stop).await {
+        Ok(_) => println!("   Stop loss set successfully!"),
+        Err(e) => println!("   Error setting stop loss: {}", e),
+    }
+
+    println!("\n6. Getting execution list...");
+    match client.get_execution_list("linear", None, None, None).await {
         Ok(executions) => {
-            if let Some(list) = executions.get("list").and_then(|v| v.as_array()) {
-                println!("   Total executions: {}", list.len());
-                for exec in list.iter().take(3) {
-                    if let Some(obj) = exec.as_object()
-                        && let (Some(order_id), Some(symbol), Some(side), Some(exec_qty)) = (
-                            obj.get("orderId").and_then(|v| v.as_str()),
-                            obj.get("symbol").and_then(|v| v.as_str()),
-                            obj.get("side").and_then(|v| v.as_str()),
-                            obj.get("execQty").and_then(|v| v.as_str()),
-                        )
-                    {
-                        println!(
-                            "     Order: {} - {} {} @ qty: {}",
-                            order_id, side, symbol, exec_qty
-                        );
-                    }
-                }
+            println!("   Total executions: {}", executions.list.len());
+            for exec in executions.list.iter().take(3) {
+                println!(
+                    "     Order: {} - {} {} @ qty: {}",
+                    exec.order_id, exec.side, exec.symbol, exec.exec_qty
+                );
             }
         }
         Err(e) => println!("   Error getting execution list: {}", e),
     }
 
-    println!("\n6. Getting execution list for BTCUSDT...");
-    match client.get_execution_list("linear", Some("BTCUSDT")).await {
+    println!("\n7. Getting execution list for BTCUSDT...");
+    match client
+        .get_execution_list("linear", Some("BTCUSDT"), Some(50), None)
+        .await
+    {
         Ok(executions) => {
-            if let Some(list) = executions.get("list").and_then(|v| v.as_array()) {
-                println!("   BTCUSDT executions: {}", list.len());
-            }
+            println!("   BTCUSDT executions: {}", executions.list.len());
         }
         Err(e) => println!("   Error getting execution list: {}", e),
     }
 
-    println!("\n7. Getting closed PnL...");
+    println!("\n8. Getting closed PnL...");
     match client.get_closed_pnl("linear", None).await {
         Ok(closed_pnl) => {
-            if let Some(list) = closed_pnl.get("list").and_then(|v| v.as_array()) {
-                println!("   Total closed PnL records: {}", list.len());
-                for pnl in list.iter().take(3) {
-                    if let Some(obj) = pnl.as_object()
-                        && let (Some(symbol), Some(side), Some(closed_pnl_value)) = (
-                            obj.get("symbol").and_then(|v| v.as_str()),
-                            obj.get("side").and_then(|v| v.as_str()),
-                            obj.get("closedPnl").and_then(|v| v.as_str()),
-                        )
-                    {
-                        println!("     {} {} - PnL: {}", side, symbol, closed_pnl_value);
-                    }
-                }
+            println!("   Total closed PnL records: {}", closed_pnl.list.len());
+            for pnl in closed_pnl.list.iter().take(3) {
+                println!("     {} {} - PnL: {}", pnl.side, pnl.symbol, pnl.closed_pnl);
             }
         }
         Err(e) => println!("   Error getting closed PnL: {}", e),
     }
 
-    println!("\n8. Getting closed PnL for BTCUSDT...");
+    println!("\n9. Getting closed PnL for BTCUSDT...");
     match client.get_closed_pnl("linear", Some("BTCUSDT")).await {
         Ok(closed_pnl) => {
-            if let Some(list) = closed_pnl.get("list").and_then(|v| v.as_array()) {
-                println!("   BTCUSDT closed PnL records: {}", list.len());
-                if let Some(first) = list.first()
-                    && let Some(obj) = first.as_object()
-                    && let Some(closed_pnl_value) = obj.get("closedPnl").and_then(|v| v.as_str())
-                {
-                    println!("   Latest closed PnL: {}", closed_pnl_value);
-                }
+            println!("   BTCUSDT closed PnL records: {}", closed_pnl.list.len());
+            if let Some(first) = closed_pnl.list.first() {
+                println!("   Latest closed PnL: {}", first.closed_pnl);
             }
         }
         Err(e) => println!("   Error getting closed PnL: {}", e),
     }
 
+    println!("\n10. Getting fee rates for BTCUSDT...");
+    match client.get_fee_rate("linear", Some("BTCUSDT"), None).await {
+        Ok(fee_rates) => {
+            for fee in &fee_rates.list {
+                println!(
+                    "   {}: maker {} / taker {}",
+                    fee.symbol, fee.maker_fee_rate, fee.taker_fee_rate
+                );
+            }
+        }
+        Err(e) => println!("   Error getting fee rates: {}", e),
+    }
+
     println!("\nAccount management examples completed!");
 
     Ok(())
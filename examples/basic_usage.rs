@@ -41,7 +41,9 @@ async fn authenticated_example() -> Result<(), Box<dyn std::error::Error>> {
         println!("Total equity: {}", account.total_equity);
     }
 
-    let positions = client.get_position("linear", None).await?;
+    let positions = client
+        .get_position("linear", None, Some("USDT"), None, None)
+        .await?;
     println!("\nOpen positions: {}", positions.list.len());
 
     Ok(())
@@ -35,7 +35,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("   Total asks: {}", orderbook.a.len());
 
     println!("\n4. Getting instrument info for linear market...");
-    let instruments = client.get_instruments("linear").await?;
+    let instruments = client.get_instruments("linear", None).await?;
     println!("   Total instruments: {}", instruments.list.len());
     if let Some(instrument) = instruments.list.first() {
         println!("   First instrument:");
@@ -50,19 +50,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let klines = client
         .get_kline("linear", "BTCUSDT", "15", None, None)
         .await?;
-    if let Some(list) = klines.get("list").and_then(|v| v.as_array()) {
-        println!("   Total klines: {}", list.len());
-        if let Some(first_kline) = list.first().and_then(|v| v.as_array())
-            && first_kline.len() >= 6
-        {
-            println!("   Latest kline:");
-            println!("     Timestamp: {}", first_kline[0]);
-            println!("     Open: {}", first_kline[1]);
-            println!("     High: {}", first_kline[2]);
-            println!("     Low: {}", first_kline[3]);
-            println!("     Close: {}", first_kline[4]);
-            println!("     Volume: {}", first_kline[5]);
-        }
+    println!("   Total klines: {}", klines.list.len());
+    if let Some(kline) = klines.list.first() {
+        println!("   Latest kline:");
+        println!("     Timestamp: {}", kline.start_time);
+        println!("     Open: {}", kline.open);
+        println!("     High: {}", kline.high);
+        println!("     Low: {}", kline.low);
+        println!("     Close: {}", kline.close);
+        println!("     Volume: {}", kline.volume);
     }
 
     println!("\n6. Getting tickers for inverse market...");
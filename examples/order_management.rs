@@ -1,31 +1,26 @@
+use rusty_bybit::types::{OrderType, Side, TimeInForce};
 use rusty_bybit::{BybitClient, CreateOrderRequest};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Bybit Order Management Examples\n");
 
-    let api_key = std::env::var("BYBIT_API_KEY").unwrap_or_else(|_| {
-        eprintln!("Warning: BYBIT_API_KEY not set, using placeholder");
-        "your_api_key".to_string()
-    });
-
-    let api_secret = std::env::var("BYBIT_API_SECRET").unwrap_or_else(|_| {
-        eprintln!("Warning: BYBIT_API_SECRET not set, using placeholder");
-        "your_api_secret".to_string()
-    });
+    if std::env::var("BYBIT_API_KEY").is_err() || std::env::var("BYBIT_API_SECRET").is_err() {
+        eprintln!("Warning: BYBIT_API_KEY/BYBIT_API_SECRET not set, private endpoints will fail");
+    }
 
-    let client = BybitClient::testnet().with_credentials(api_key, api_secret);
+    let client = BybitClient::from_env()?;
 
     println!("1. Creating a limit order...");
     let limit_order = CreateOrderRequest::builder()
         .category("linear")
         .symbol("BTCUSDT")
-        .side("Buy")
-        .order_type("Limit")
+        .side(Side::Buy)
+        .order_type(OrderType::Limit)
         .qty("0.001")
         .price("28000")
-        .time_in_force("GTC")
-        .build();
+        .time_in_force(TimeInForce::GTC)
+        .build()?;
 
     match client.create_order(&limit_order).await {
         Ok(response) => {
@@ -40,10 +35,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let market_order = CreateOrderRequest::builder()
         .category("linear")
         .symbol("BTCUSDT")
-        .side("Buy")
-        .order_type("Market")
+        .side(Side::Buy)
+        .order_type(OrderType::Market)
         .qty("0.001")
-        .build();
+        .build()?;
 
     match client.create_order(&market_order).await {
         Ok(response) => {
@@ -57,13 +52,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let order_with_tp_sl = CreateOrderRequest::builder()
         .category("linear")
         .symbol("BTCUSDT")
-        .side("Buy")
-        .order_type("Limit")
+        .side(Side::Buy)
+        .order_type(OrderType::Limit)
         .qty("0.001")
         .price("28000")
         .take_profit("30000")
         .stop_loss("27000")
-        .build();
+        .build()?;
 
     match client.create_order(&order_with_tp_sl).await {
         Ok(response) => {
@@ -77,11 +72,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let reduce_only_order = CreateOrderRequest::builder()
         .category("linear")
         .symbol("BTCUSDT")
-        .side("Sell")
-        .order_type("Market")
+        .side(Side::Sell)
+        .order_type(OrderType::Market)
         .qty("0.001")
         .reduce_only(true)
-        .build();
+        .build()?;
 
     match client.create_order(&reduce_only_order).await {
         Ok(response) => {
@@ -95,12 +90,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let order_with_link_id = CreateOrderRequest::builder()
         .category("linear")
         .symbol("BTCUSDT")
-        .side("Buy")
-        .order_type("Limit")
+        .side(Side::Buy)
+        .order_type(OrderType::Limit)
         .qty("0.001")
         .price("28000")
         .order_link_id("my_custom_order_link_id")
-        .build();
+        .build()?;
 
     match client.create_order(&order_with_link_id).await {
         Ok(response) => {
@@ -159,8 +154,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     println!("\n9. Canceling all orders for a symbol...");
-    match client.cancel_all_orders("linear", "BTCUSDT").await {
-        Ok(_) => println!("   All orders for BTCUSDT canceled successfully!"),
+    match client
+        .cancel_all_orders("linear", Some("BTCUSDT"), None, None, None)
+        .await
+    {
+        Ok(result) => println!("   Canceled {} orders for BTCUSDT", result.list.len()),
         Err(e) => println!("   Error canceling all orders: {}", e),
     }
 
@@ -19,8 +19,43 @@
 //! ```
 
 use crate::client::BybitClient;
-use crate::error::Result;
-use crate::types::{PositionList, WalletBalance};
+use crate::error::{BybitError, Result};
+use crate::pagination::paginate;
+use crate::types::{
+    AccountInfo, BorrowHistoryList, Category, ClosedPnl, ClosedPnlList, CollateralInfoList,
+    Execution, ExecutionList, FeeRateList, MarginMode, MarginUpdate, Position, PositionList,
+    PositionMode, RiskLimitUpdate, SpotMarginState, TradeMode, TradingStopRequest, TransactionLog,
+    TransactionLogList, WalletBalance,
+};
+use futures_util::Stream;
+
+const MAX_LEVERAGE: f64 = 200.0;
+
+fn format_leverage(field: &str, leverage: f64) -> Result<String> {
+    if !(1.0..=MAX_LEVERAGE).contains(&leverage) {
+        return Err(BybitError::InvalidParameter(format!(
+            "{} must be between 1 and {}, got {}",
+            field, MAX_LEVERAGE, leverage
+        )));
+    }
+    crate::validation::round_to_step(leverage, "0.01")
+}
+
+/// Bybit takes a signed decimal: positive adds margin, negative reduces it.
+/// A leading `+` or a zero amount is rejected by the exchange.
+fn validate_margin(margin: &str) -> Result<()> {
+    let valid = !margin.starts_with('+')
+        && margin
+            .parse::<f64>()
+            .is_ok_and(|value| value.is_finite() && value != 0.0);
+    if !valid {
+        return Err(BybitError::InvalidParameter(format!(
+            "margin must be a non-zero decimal, negative to reduce, got {:?}",
+            margin
+        )));
+    }
+    Ok(())
+}
 
 impl BybitClient {
     pub async fn get_wallet_balance(&self, account_type: Option<&str>) -> Result<WalletBalance> {
@@ -28,57 +63,1276 @@ impl BybitClient {
         self.get("/v5/account/wallet-balance", query).await
     }
 
-    pub async fn get_position(&self, category: &str, symbol: Option<&str>) -> Result<PositionList> {
+    /// Margin mode and unified-account status, e.g. to pick the `accountType`
+    /// for [`get_wallet_balance`](Self::get_wallet_balance)
+    pub async fn get_account_info(&self) -> Result<AccountInfo> {
+        self.get("/v5/account/info", None).await
+    }
+
+    /// Fee rates for `symbol`, or for every symbol in the category when unset
+    ///
+    /// Options fees are quoted per `base_coin` instead of per symbol.
+    pub async fn get_fee_rate(
+        &self,
+        category: impl AsRef<str>,
+        symbol: Option<&str>,
+        base_coin: Option<&str>,
+    ) -> Result<FeeRateList> {
+        let category = Category::try_from(category.as_ref())?.as_str();
         let mut query = vec![("category", category)];
         if let Some(s) = symbol {
             query.push(("symbol", s));
         }
+        if let Some(c) = base_coin {
+            query.push(("baseCoin", c));
+        }
+        self.get("/v5/account/fee-rate", Some(query)).await
+    }
+
+    /// Positions of `symbol`, or every open position settled in `settle_coin`
+    ///
+    /// `linear` needs `symbol` or `settle_coin`. Without a symbol Bybit only
+    /// returns positions with a non-zero size, up to `limit` (20 by default,
+    /// at most 200) per page; follow `next_page_cursor` with `cursor` or use
+    /// [`get_position_paged`](Self::get_position_paged).
+    pub async fn get_position(
+        &self,
+        category: impl AsRef<str>,
+        symbol: Option<&str>,
+        settle_coin: Option<&str>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<PositionList> {
+        let category = Category::try_from(category.as_ref())?;
+        if category == Category::Linear && symbol.is_none() && settle_coin.is_none() {
+            return Err(BybitError::InvalidParameter(
+                "linear positions need a symbol or settle coin".to_string(),
+            ));
+        }
+
+        let mut query = vec![("category", category.as_str())];
+        if let Some(s) = symbol {
+            query.push(("symbol", s));
+        }
+        if let Some(c) = settle_coin {
+            query.push(("settleCoin", c));
+        }
+        let limit = limit.map(|l| l.to_string());
+        if let Some(l) = &limit {
+            query.push(("limit", l.as_str()));
+        }
+        if let Some(c) = cursor {
+            query.push(("cursor", c));
+        }
         self.get("/v5/position/list", Some(query)).await
     }
 
+    /// Streams positions, following `nextPageCursor` until the last page;
+    /// `page_size` is passed as `limit` on every request
+    pub fn get_position_paged<'a>(
+        &'a self,
+        category: impl AsRef<str>,
+        symbol: Option<&'a str>,
+        settle_coin: Option<&'a str>,
+        page_size: Option<u32>,
+    ) -> impl Stream<Item = Result<Position>> + 'a {
+        let category = category.as_ref().to_string();
+        paginate(move |cursor| {
+            let category = category.clone();
+            async move {
+                self.get_position(category, symbol, settle_coin, page_size, cursor.as_deref())
+                    .await
+            }
+        })
+    }
+
     pub async fn set_leverage(
         &self,
-        category: &str,
+        category: impl AsRef<str>,
         symbol: &str,
         buy_leverage: &str,
         sell_leverage: &str,
-    ) -> Result<serde_json::Value> {
+    ) -> Result<()> {
+        let category = Category::try_from(category.as_ref())?.as_str();
         let body = serde_json::json!({
             "category": category,
             "symbol": symbol,
             "buyLeverage": buy_leverage,
             "sellLeverage": sell_leverage,
         });
-        self.post("/v5/position/set-leverage", Some(body)).await
+        self.post_empty("/v5/position/set-leverage", body).await
+    }
+
+    /// Like [`set_leverage`](Self::set_leverage), taking numeric leverage
+    ///
+    /// Each value must lie within `1..=200` and is rounded to Bybit's `0.01`
+    /// leverage step. A `110043` response (leverage already at that value) is
+    /// treated as success.
+    pub async fn set_leverage_f64(
+        &self,
+        category: impl AsRef<str>,
+        symbol: &str,
+        buy_leverage: f64,
+        sell_leverage: f64,
+    ) -> Result<()> {
+        let buy_leverage = format_leverage("buy_leverage", buy_leverage)?;
+        let sell_leverage = format_leverage("sell_leverage", sell_leverage)?;
+        match self
+            .set_leverage(category, symbol, &buy_leverage, &sell_leverage)
+            .await
+        {
+            Ok(_)
+            | Err(BybitError::ApiError {
+                ret_code: 110043, ..
+            }) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Sets the margin mode of the whole unified account
+    ///
+    /// Bybit rejects the switch while it would be unsafe (for example with open
+    /// positions or orders); those rejections surface as `ApiError`.
+    pub async fn set_margin_mode(&self, mode: MarginMode) -> Result<()> {
+        let body = serde_json::json!({ "setMarginMode": mode });
+        self.post_empty("/v5/account/set-margin-mode", body).await
+    }
+
+    /// Switches a single symbol between cross and isolated margin
+    ///
+    /// Bybit requires both leverages even when only the mode changes.
+    pub async fn switch_margin_mode(
+        &self,
+        category: impl AsRef<str>,
+        symbol: &str,
+        trade_mode: TradeMode,
+        buy_leverage: &str,
+        sell_leverage: &str,
+    ) -> Result<()> {
+        let category = Category::try_from(category.as_ref())?.as_str();
+        let body = serde_json::json!({
+            "category": category,
+            "symbol": symbol,
+            "tradeMode": trade_mode,
+            "buyLeverage": buy_leverage,
+            "sellLeverage": sell_leverage,
+        });
+        self.post_empty("/v5/position/switch-isolated", body).await
+    }
+
+    /// Switches between one-way and hedge mode
+    ///
+    /// Applies to a single `symbol` or to every symbol settled in `coin`.
+    /// Exactly one of them must be given; Bybit would silently ignore `coin`
+    /// when both are set.
+    pub async fn switch_position_mode(
+        &self,
+        category: impl AsRef<str>,
+        symbol: Option<&str>,
+        coin: Option<&str>,
+        mode: PositionMode,
+    ) -> Result<()> {
+        let category = Category::try_from(category.as_ref())?.as_str();
+        if symbol.is_some() == coin.is_some() {
+            return Err(BybitError::InvalidParameter(
+                "switch_position_mode requires exactly one of symbol or coin".to_string(),
+            ));
+        }
+
+        let mut body = serde_json::json!({
+            "category": category,
+            "mode": mode,
+        });
+        if let Some(s) = symbol {
+            body["symbol"] = s.into();
+        }
+        if let Some(c) = coin {
+            body["coin"] = c.into();
+        }
+        self.post_empty("/v5/position/switch-mode", body).await
+    }
+
+    pub async fn set_trading_stop(&self, request: &TradingStopRequest) -> Result<()> {
+        let body = serde_json::to_value(request)?;
+        self.post_empty("/v5/position/trading-stop", body).await
+    }
+
+    /// Adds margin to an isolated position, or reduces it when `margin` is
+    /// negative (e.g. `"-10"`)
+    ///
+    /// Only `linear` and `inverse` positions carry isolated margin.
+    /// `position_idx` selects the side in hedge mode.
+    pub async fn add_margin(
+        &self,
+        category: impl AsRef<str>,
+        symbol: &str,
+        margin: &str,
+        position_idx: Option<u64>,
+    ) -> Result<MarginUpdate> {
+        let category = Category::try_from(category.as_ref())?;
+        if !matches!(category, Category::Linear | Category::Inverse) {
+            return Err(BybitError::InvalidParameter(format!(
+                "margin can only be adjusted for linear and inverse, got {}",
+                category.as_str()
+            )));
+        }
+        validate_margin(margin)?;
+        let mut body = serde_json::json!({
+            "category": category.as_str(),
+            "symbol": symbol,
+            "margin": margin,
+        });
+        if let Some(idx) = position_idx {
+            body["positionIdx"] = idx.into();
+        }
+        self.post("/v5/position/add-margin", Some(body)).await
+    }
+
+    /// Moves a position to the risk limit tier `risk_id`, as listed by
+    /// [`get_risk_limit`](Self::get_risk_limit)
+    ///
+    /// `position_idx` selects the side in hedge mode.
+    pub async fn set_risk_limit(
+        &self,
+        category: impl AsRef<str>,
+        symbol: &str,
+        risk_id: u64,
+        position_idx: Option<u64>,
+    ) -> Result<RiskLimitUpdate> {
+        let category = Category::try_from(category.as_ref())?.as_str();
+        let mut body = serde_json::json!({
+            "category": category,
+            "symbol": symbol,
+            "riskId": risk_id,
+        });
+        if let Some(idx) = position_idx {
+            body["positionIdx"] = idx.into();
+        }
+        self.post("/v5/position/set-risk-limit", Some(body)).await
     }
 
     pub async fn get_execution_list(
         &self,
-        category: &str,
+        category: impl AsRef<str>,
         symbol: Option<&str>,
-    ) -> Result<serde_json::Value> {
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<ExecutionList> {
+        let category = Category::try_from(category.as_ref())?.as_str();
+        let limit = limit.map(|l| l.to_string());
+
         let mut query = vec![("category", category)];
         if let Some(s) = symbol {
             query.push(("symbol", s));
         }
+        if let Some(l) = &limit {
+            query.push(("limit", l.as_str()));
+        }
+        if let Some(c) = cursor {
+            query.push(("cursor", c));
+        }
         self.get("/v5/execution/list", Some(query)).await
     }
 
+    /// Streams the execution history, following `nextPageCursor` until the
+    /// last page; `page_size` is passed as `limit` on every request
+    pub fn get_execution_list_paged<'a>(
+        &'a self,
+        category: impl AsRef<str>,
+        symbol: Option<&'a str>,
+        page_size: Option<u32>,
+    ) -> impl Stream<Item = Result<Execution>> + 'a {
+        let category = category.as_ref().to_string();
+        paginate(move |cursor| {
+            let category = category.clone();
+            async move {
+                self.get_execution_list(category, symbol, page_size, cursor.as_deref())
+                    .await
+            }
+        })
+    }
+
     pub async fn get_closed_pnl(
         &self,
-        category: &str,
+        category: impl AsRef<str>,
+        symbol: Option<&str>,
+    ) -> Result<ClosedPnlList> {
+        let category = Category::try_from(category.as_ref())?.as_str();
+        let mut query = vec![("category", category)];
+        if let Some(s) = symbol {
+            query.push(("symbol", s));
+        }
+        self.get("/v5/position/closed-pnl", Some(query)).await
+    }
+
+    /// Like [`get_closed_pnl`](Self::get_closed_pnl), bounded by time and
+    /// paged with `limit` and `cursor`
+    ///
+    /// `start_time` and `end_time` are in milliseconds and may span at most 7
+    /// days; without them Bybit only returns the last 7 days.
+    pub async fn get_closed_pnl_range(
+        &self,
+        category: impl AsRef<str>,
         symbol: Option<&str>,
-    ) -> Result<serde_json::Value> {
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<ClosedPnlList> {
+        let category = Category::try_from(category.as_ref())?.as_str();
+        let start_time = start_time.map(|t| t.to_string());
+        let end_time = end_time.map(|t| t.to_string());
+        let limit = limit.map(|l| l.to_string());
+
         let mut query = vec![("category", category)];
         if let Some(s) = symbol {
             query.push(("symbol", s));
         }
+        if let Some(s) = &start_time {
+            query.push(("startTime", s.as_str()));
+        }
+        if let Some(e) = &end_time {
+            query.push(("endTime", e.as_str()));
+        }
+        if let Some(l) = &limit {
+            query.push(("limit", l.as_str()));
+        }
+        if let Some(c) = cursor {
+            query.push(("cursor", c));
+        }
         self.get("/v5/position/closed-pnl", Some(query)).await
     }
+
+    /// Streams closed PnL records in a time range, following `nextPageCursor`
+    /// until the last page; `page_size` is passed as `limit` on every request
+    pub fn get_closed_pnl_paged<'a>(
+        &'a self,
+        category: impl AsRef<str>,
+        symbol: Option<&'a str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        page_size: Option<u32>,
+    ) -> impl Stream<Item = Result<ClosedPnl>> + 'a {
+        let category = category.as_ref().to_string();
+        paginate(move |cursor| {
+            let category = category.clone();
+            async move {
+                self.get_closed_pnl_range(
+                    category,
+                    symbol,
+                    start_time,
+                    end_time,
+                    page_size,
+                    cursor.as_deref(),
+                )
+                .await
+            }
+        })
+    }
+
+    /// Sets the leverage used for spot margin trading, from `"2"` to `"10"`
+    pub async fn set_spot_margin_leverage(&self, leverage: &str) -> Result<()> {
+        let body = serde_json::json!({ "leverage": leverage });
+        self.post_empty("/v5/spot-margin-trade/set-leverage", body)
+            .await
+    }
+
+    /// Turns spot margin trading on or off for the unified account
+    pub async fn toggle_spot_margin_trade(&self, switch: bool) -> Result<SpotMarginState> {
+        let body = serde_json::json!({ "spotMarginMode": if switch { "1" } else { "0" } });
+        self.post("/v5/spot-margin-trade/switch-mode", Some(body))
+            .await
+    }
+
+    /// Collateral ratio and borrowing limits of `currency`, or of every coin
+    /// when `None` (unified accounts only)
+    pub async fn get_collateral_info(&self, currency: Option<&str>) -> Result<CollateralInfoList> {
+        let mut query = Vec::new();
+        if let Some(c) = currency {
+            query.push(("currency", c));
+        }
+        self.get("/v5/account/collateral-info", Some(query)).await
+    }
+
+    /// Hourly interest charged on borrowed coins, newest first
+    ///
+    /// `start_time` and `end_time` are in milliseconds and may span at most 30
+    /// days.
+    pub async fn get_borrow_history(
+        &self,
+        currency: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<BorrowHistoryList> {
+        let start_time = start_time.map(|t| t.to_string());
+        let end_time = end_time.map(|t| t.to_string());
+        let limit = limit.map(|l| l.to_string());
+
+        let mut query = Vec::new();
+        if let Some(c) = currency {
+            query.push(("currency", c));
+        }
+        if let Some(s) = &start_time {
+            query.push(("startTime", s.as_str()));
+        }
+        if let Some(e) = &end_time {
+            query.push(("endTime", e.as_str()));
+        }
+        if let Some(l) = &limit {
+            query.push(("limit", l.as_str()));
+        }
+        if let Some(c) = cursor {
+            query.push(("cursor", c));
+        }
+        self.get("/v5/account/borrow-history", Some(query)).await
+    }
+
+    /// Every balance-changing event (trades, funding, fees, transfers), newest
+    /// first
+    ///
+    /// `account_type` defaults to `UNIFIED`; `start_time` and `end_time` are in
+    /// milliseconds and may span at most 7 days.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_transaction_log(
+        &self,
+        account_type: Option<&str>,
+        category: Option<&str>,
+        currency: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<TransactionLogList> {
+        let category = category.map(Category::try_from).transpose()?;
+        let start_time = start_time.map(|t| t.to_string());
+        let end_time = end_time.map(|t| t.to_string());
+        let limit = limit.map(|l| l.to_string());
+
+        let mut query = Vec::new();
+        if let Some(a) = account_type {
+            query.push(("accountType", a));
+        }
+        if let Some(c) = &category {
+            query.push(("category", c.as_str()));
+        }
+        if let Some(c) = currency {
+            query.push(("currency", c));
+        }
+        if let Some(s) = &start_time {
+            query.push(("startTime", s.as_str()));
+        }
+        if let Some(e) = &end_time {
+            query.push(("endTime", e.as_str()));
+        }
+        if let Some(l) = &limit {
+            query.push(("limit", l.as_str()));
+        }
+        if let Some(c) = cursor {
+            query.push(("cursor", c));
+        }
+        self.get("/v5/account/transaction-log", Some(query)).await
+    }
+
+    /// Streams the transaction log, following `nextPageCursor` until the last
+    /// page; `page_size` is passed as `limit` on every request
+    pub fn get_transaction_log_paged<'a>(
+        &'a self,
+        account_type: Option<&'a str>,
+        category: Option<&'a str>,
+        currency: Option<&'a str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        page_size: Option<u32>,
+    ) -> impl Stream<Item = Result<TransactionLog>> + 'a {
+        paginate(move |cursor| async move {
+            self.get_transaction_log(
+                account_type,
+                category,
+                currency,
+                start_time,
+                end_time,
+                page_size,
+                cursor.as_deref(),
+            )
+            .await
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use mockito::Matcher;
+
     #[test]
     fn test_account_module_exists() {}
+
+    #[tokio::test]
+    async fn test_get_account_info() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/account/info")
+            .with_body(
+                r#"{"retCode":0,"retMsg":"success","result":{"marginMode":"REGULAR_MARGIN","updatedTime":"1697078946000","unifiedMarginStatus":4,"dcpStatus":"OFF","timeWindow":10,"smpGroup":0,"isMasterTrader":false,"spotHedgingStatus":"OFF"},"retExtInfo":{},"time":1697078946000}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let info = client.get_account_info().await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(info.margin_mode, "REGULAR_MARGIN");
+        assert!(info.is_unified());
+    }
+
+    #[tokio::test]
+    async fn test_get_fee_rate_for_all_symbols() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/account/fee-rate")
+            .match_query(Matcher::Exact("category=spot".into()))
+            .with_body(
+                r#"{
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {
+                        "list": [
+                            {"symbol": "BTCUSDT", "takerFeeRate": "0.001", "makerFeeRate": "0.001"},
+                            {"symbol": "ETHUSDT", "takerFeeRate": "0.001", "makerFeeRate": "0.001"}
+                        ]
+                    },
+                    "retExtInfo": {},
+                    "time": 1676360412576
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let fee_rates = client.get_fee_rate("spot", None, None).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(fee_rates.list.len(), 2);
+        assert_eq!(fee_rates.list[1].symbol, "ETHUSDT");
+    }
+
+    #[tokio::test]
+    async fn test_get_fee_rate_for_symbol() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/account/fee-rate")
+            .match_query(Matcher::Exact("category=linear&symbol=ETHUSDT".into()))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"OK","result":{"list":[{"symbol":"ETHUSDT","takerFeeRate":"0.0006","makerFeeRate":"0.0001"}]},"retExtInfo":{},"time":1676360412576}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let fee_rates = client
+            .get_fee_rate("linear", Some("ETHUSDT"), None)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(fee_rates.list[0].maker_fee_rate, "0.0001");
+    }
+
+    #[tokio::test]
+    async fn test_set_leverage_f64() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/position/set-leverage")
+            .match_body(Matcher::Json(serde_json::json!({
+                "category": "linear",
+                "symbol": "BTCUSDT",
+                "buyLeverage": "12.50",
+                "sellLeverage": "10.00",
+            })))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"OK","result":{},"retExtInfo":{},"time":1672281607343}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        client
+            .set_leverage_f64("linear", "BTCUSDT", 12.5, 10.0)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_set_leverage_f64_treats_unchanged_as_success() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/v5/position/set-leverage")
+            .with_body(r#"{"retCode":110043,"retMsg":"Set leverage not modified","result":{},"retExtInfo":{},"time":1672281607343}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        assert!(
+            client
+                .set_leverage_f64("linear", "BTCUSDT", 10.0, 10.0)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_leverage_f64_rejects_out_of_range() {
+        let client = BybitClient::new("http://127.0.0.1:9".to_string())
+            .with_credentials("key".into(), "secret".into());
+        for (buy, sell) in [(0.5, 10.0), (10.0, 250.0), (f64::NAN, 10.0)] {
+            let result = client
+                .set_leverage_f64("linear", "BTCUSDT", buy, sell)
+                .await;
+            assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_margin_mode() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/account/set-margin-mode")
+            .match_body(Matcher::Json(serde_json::json!({
+                "setMarginMode": "PORTFOLIO_MARGIN",
+            })))
+            .with_body(r#"{"retCode":0,"retMsg":"Request accepted","result":{"reasons":[]},"retExtInfo":{},"time":1700000000000}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        client
+            .set_margin_mode(MarginMode::PortfolioMargin)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_switch_margin_mode() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/position/switch-isolated")
+            .match_body(Matcher::Json(serde_json::json!({
+                "category": "linear",
+                "symbol": "ETHUSDT",
+                "tradeMode": 1,
+                "buyLeverage": "10",
+                "sellLeverage": "10",
+            })))
+            .with_body(
+                r#"{"retCode":110026,"retMsg":"Cross/isolated margin mode is not modified","result":{},"retExtInfo":{},"time":1700000000000}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let result = client
+            .switch_margin_mode("linear", "ETHUSDT", TradeMode::Isolated, "10", "10")
+            .await;
+
+        mock.assert_async().await;
+        assert!(matches!(
+            result,
+            Err(BybitError::ApiError {
+                ret_code: 110026,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_switch_position_mode() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/position/switch-mode")
+            .match_body(Matcher::Json(serde_json::json!({
+                "category": "inverse",
+                "coin": "BTC",
+                "mode": 3,
+            })))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"OK","result":{},"retExtInfo":{},"time":1675249072814}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        client
+            .switch_position_mode("inverse", None, Some("BTC"), PositionMode::BothSides)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_switch_position_mode_requires_exactly_one_of_symbol_or_coin() {
+        let client = BybitClient::new("http://127.0.0.1:9".to_string());
+
+        let result = client
+            .switch_position_mode("linear", None, None, PositionMode::MergedSingle)
+            .await;
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+
+        let result = client
+            .switch_position_mode(
+                "linear",
+                Some("BTCUSDT"),
+                Some("USDT"),
+                PositionMode::BothSides,
+            )
+            .await;
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_set_trading_stop() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/position/trading-stop")
+            .match_body(Matcher::Json(serde_json::json!({
+                "category": "linear",
+                "symbol": "BTCUSDT",
+                "positionIdx": 0,
+                "stopLoss": "27000",
+                "slTriggerBy": "MarkPrice",
+            })))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"OK","result":{},"retExtInfo":{},"time":1672284125299}"#,
+            )
+            .create_async()
+            .await;
+
+        let request = TradingStopRequest::builder()
+            .symbol("BTCUSDT")
+            .stop_loss("27000")
+            .sl_trigger_by("MarkPrice")
+            .build()
+            .unwrap();
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        client.set_trading_stop(&request).await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_add_margin() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/position/add-margin")
+            .match_body(Matcher::Json(serde_json::json!({
+                "category": "linear",
+                "symbol": "BTCUSDT",
+                "margin": "-10",
+                "positionIdx": 1,
+            })))
+            .with_body(
+                r#"{
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {
+                        "category": "linear",
+                        "symbol": "BTCUSDT",
+                        "positionIdx": 1,
+                        "riskId": 1,
+                        "riskLimitValue": "2000000",
+                        "size": "0.1",
+                        "avgPrice": "30000",
+                        "liqPrice": "27000",
+                        "bustPrice": "26800",
+                        "markPrice": "30100",
+                        "positionValue": "3000",
+                        "leverage": "10",
+                        "autoAddMargin": 0,
+                        "positionStatus": "Normal",
+                        "positionIM": "290",
+                        "positionMM": "15",
+                        "takeProfit": "0",
+                        "stopLoss": "0",
+                        "trailingStop": "0",
+                        "unrealisedPnl": "10",
+                        "cumRealisedPnl": "0",
+                        "createdTime": "1672121182216",
+                        "updatedTime": "1672134421887"
+                    },
+                    "retExtInfo": {},
+                    "time": 1672134421888
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let update = client
+            .add_margin("linear", "BTCUSDT", "-10", Some(1))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(update.position_idx, 1);
+        assert_eq!(update.position_im_f64().unwrap(), 290.0);
+        assert_eq!(update.liq_price, "27000");
+    }
+
+    #[tokio::test]
+    async fn test_add_margin_rejects_invalid_amount_or_category() {
+        let client = BybitClient::new("http://127.0.0.1:9".to_string())
+            .with_credentials("key".into(), "secret".into());
+
+        for margin in ["0", "+10", "abc", "", "NaN"] {
+            assert!(matches!(
+                client.add_margin("linear", "BTCUSDT", margin, None).await,
+                Err(BybitError::InvalidParameter(_))
+            ));
+        }
+        assert!(matches!(
+            client.add_margin("spot", "BTCUSDT", "10", None).await,
+            Err(BybitError::InvalidParameter(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_set_risk_limit() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/position/set-risk-limit")
+            .match_body(Matcher::Json(serde_json::json!({
+                "category": "linear",
+                "symbol": "BTCUSDT",
+                "riskId": 4,
+                "positionIdx": 0,
+            })))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"OK","result":{"category":"linear","riskId":4,"riskLimitValue":"8000000"},"retExtInfo":{},"time":1672282269774}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let result = client
+            .set_risk_limit("linear", "BTCUSDT", 4, Some(0))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(result.risk_id, 4);
+        assert_eq!(result.risk_limit_value_f64().unwrap(), 8000000.0);
+    }
+
+    #[tokio::test]
+    async fn test_get_execution_list_paginates() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/execution/list")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("category".into(), "linear".into()),
+                Matcher::UrlEncoded("symbol".into(), "BTCUSDT".into()),
+                Matcher::UrlEncoded("limit".into(), "1".into()),
+                Matcher::UrlEncoded("cursor".into(), "page-1".into()),
+            ]))
+            .with_body(
+                r#"{
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {
+                        "category": "linear",
+                        "list": [{
+                            "symbol": "BTCUSDT",
+                            "orderId": "fdd92d4c-4b3b-4a63-9d6b-5b8dc2f2c2c1",
+                            "orderLinkId": "",
+                            "side": "Buy",
+                            "orderPrice": "28000",
+                            "orderQty": "0.001",
+                            "execId": "e0cbe81d-0f18-5866-9415-cf319b5dab3b",
+                            "execPrice": "28000",
+                            "execQty": "0.001",
+                            "execFee": "0.0154",
+                            "execTime": "1672211918471",
+                            "execType": "Trade",
+                            "feeRate": "0.00055",
+                            "isMaker": false
+                        }],
+                        "nextPageCursor": "page-2"
+                    },
+                    "retExtInfo": {},
+                    "time": 1672211918471
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let executions = client
+            .get_execution_list("linear", Some("BTCUSDT"), Some(1), Some("page-1"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(executions.list.len(), 1);
+        assert_eq!(executions.list[0].exec_qty, "0.001");
+        assert_eq!(executions.next_page_cursor.as_deref(), Some("page-2"));
+    }
+
+    /// Response body for one page of closed PnL records with the given order ids
+    fn closed_pnl_page(order_ids: &[&str], next_page_cursor: &str) -> String {
+        let list: Vec<serde_json::Value> = order_ids
+            .iter()
+            .map(|id| {
+                serde_json::json!({
+                    "symbol": "BTCUSDT",
+                    "orderType": "Market",
+                    "leverage": "10",
+                    "updatedTime": "1672214887236",
+                    "side": "Sell",
+                    "orderId": id,
+                    "closedPnl": "-1.5",
+                    "avgEntryPrice": "27000",
+                    "qty": "0.01",
+                    "cumEntryValue": "270",
+                    "createdTime": "1672214887231",
+                    "orderPrice": "26000",
+                    "closedSize": "0.01",
+                    "avgExitPrice": "26850",
+                    "execType": "Trade",
+                    "fillCount": "1",
+                    "cumExitValue": "268.5"
+                })
+            })
+            .collect();
+        crate::test_support::page_body(list, next_page_cursor)
+    }
+
+    #[tokio::test]
+    async fn test_get_closed_pnl_range() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/position/closed-pnl")
+            .match_query(Matcher::Exact(
+                "category=linear&symbol=BTCUSDT&startTime=1672000000000&endTime=1672600000000&limit=50&cursor=abc"
+                    .into(),
+            ))
+            .with_body(closed_pnl_page(&["1"], ""))
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let pnl = client
+            .get_closed_pnl_range(
+                "linear",
+                Some("BTCUSDT"),
+                Some(1672000000000),
+                Some(1672600000000),
+                Some(50),
+                Some("abc"),
+            )
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(pnl.list.len(), 1);
+        assert_eq!(pnl.list[0].order_id, "1");
+    }
+
+    #[tokio::test]
+    async fn test_get_closed_pnl_paged() {
+        use futures_util::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+        let first = server
+            .mock("GET", "/v5/position/closed-pnl")
+            .match_query(Matcher::Exact(
+                "category=linear&startTime=1672000000000&limit=2".into(),
+            ))
+            .with_body(closed_pnl_page(&["1", "2"], "page-2"))
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/v5/position/closed-pnl")
+            .match_query(Matcher::Exact(
+                "category=linear&startTime=1672000000000&limit=2&cursor=page-2".into(),
+            ))
+            .with_body(closed_pnl_page(&["3"], ""))
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let ids: Vec<String> = client
+            .get_closed_pnl_paged("linear", None, Some(1672000000000), None, Some(2))
+            .map(|pnl| pnl.unwrap().order_id)
+            .collect()
+            .await;
+
+        first.assert_async().await;
+        second.assert_async().await;
+        assert_eq!(ids, vec!["1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_position_by_settle_coin() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/position/list")
+            .match_query(Matcher::Exact(
+                "category=linear&settleCoin=USDT&limit=1&cursor=page-1".into(),
+            ))
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"list":[{"positionIdx":0,"symbol":"ETHUSDT","side":"Buy","size":"0.5","avgPrice":"1800","positionValue":"900","positionStatus":"Normal","leverage":"5","markPrice":"1810","liqPrice":"1450","unrealisedPnl":"5","createdTime":"1676538056258","updatedTime":"1697673600012"}],"nextPageCursor":"page-2","category":"linear"},"retExtInfo":{},"time":1697684980172}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let positions = client
+            .get_position("linear", None, Some("USDT"), Some(1), Some("page-1"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(positions.list[0].symbol, "ETHUSDT");
+        assert_eq!(positions.list[0].liq_price.as_deref(), Some("1450"));
+        assert_eq!(positions.next_page_cursor.as_deref(), Some("page-2"));
+    }
+
+    #[tokio::test]
+    async fn test_get_position_paged() {
+        use futures_util::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+        let first = server
+            .mock("GET", "/v5/position/list")
+            .match_query(Matcher::Exact("category=linear&settleCoin=USDT&limit=1".into()))
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"list":[{"positionIdx":0,"symbol":"BTCUSDT","side":"Buy","size":"0.01","positionValue":"270","positionStatus":"Normal","unrealisedPnl":"0"}],"nextPageCursor":"page-2","category":"linear"},"retExtInfo":{},"time":1}"#)
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/v5/position/list")
+            .match_query(Matcher::Exact(
+                "category=linear&settleCoin=USDT&limit=1&cursor=page-2".into(),
+            ))
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"list":[{"positionIdx":0,"symbol":"ETHUSDT","side":"Sell","size":"0.5","positionValue":"900","positionStatus":"Normal","unrealisedPnl":"0"}],"nextPageCursor":"","category":"linear"},"retExtInfo":{},"time":1}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let symbols: Vec<String> = client
+            .get_position_paged("linear", None, Some("USDT"), Some(1))
+            .map(|position| position.unwrap().symbol)
+            .collect()
+            .await;
+
+        first.assert_async().await;
+        second.assert_async().await;
+        assert_eq!(symbols, vec!["BTCUSDT", "ETHUSDT"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_position_requires_symbol_or_settle_coin_for_linear() {
+        let client = BybitClient::new("http://127.0.0.1:9".to_string());
+
+        let result = client.get_position("linear", None, None, None, None).await;
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_set_spot_margin_leverage() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/spot-margin-trade/set-leverage")
+            .match_body(Matcher::Json(serde_json::json!({ "leverage": "4" })))
+            .with_body(r#"{"retCode":0,"retMsg":"success","result":{},"retExtInfo":{},"time":1672710944282}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        client.set_spot_margin_leverage("4").await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_toggle_spot_margin_trade_encodes_switch() {
+        let mut server = mockito::Server::new_async().await;
+        let on = server
+            .mock("POST", "/v5/spot-margin-trade/switch-mode")
+            .match_body(Matcher::Json(serde_json::json!({ "spotMarginMode": "1" })))
+            .with_body(r#"{"retCode":0,"retMsg":"success","result":{"spotMarginMode":"1"},"retExtInfo":{},"time":1672297794480}"#)
+            .create_async()
+            .await;
+        let off = server
+            .mock("POST", "/v5/spot-margin-trade/switch-mode")
+            .match_body(Matcher::Json(serde_json::json!({ "spotMarginMode": "0" })))
+            .with_body(r#"{"retCode":0,"retMsg":"success","result":{"spotMarginMode":"0"},"retExtInfo":{},"time":1672297794480}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let state = client.toggle_spot_margin_trade(true).await.unwrap();
+        assert!(state.is_enabled());
+        assert_eq!(state.spot_leverage, "");
+        let state = client.toggle_spot_margin_trade(false).await.unwrap();
+        assert!(!state.is_enabled());
+
+        on.assert_async().await;
+        off.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_collateral_info() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/account/collateral-info")
+            .match_query(Matcher::Exact("currency=BTC".into()))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"OK","result":{"list":[{"availableToBorrow":"3","freeBorrowingAmount":"","freeBorrowAmount":"0","maxBorrowingAmount":"3","hourlyBorrowRate":"0.00000147","borrowUsageRate":"0","collateralSwitch":true,"borrowAmount":"0","borrowable":true,"currency":"BTC","marginCollateral":true,"freeBorrowingLimit":"0","collateralRatio":"0.95"}]},"retExtInfo":{},"time":1691565901952}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let info = client.get_collateral_info(Some("BTC")).await.unwrap();
+
+        mock.assert_async().await;
+        let btc = &info.list[0];
+        assert_eq!(btc.currency, "BTC");
+        assert_eq!(btc.collateral_ratio_f64().unwrap(), 0.95);
+        assert_eq!(btc.available_to_borrow_f64().unwrap(), 3.0);
+        assert!(btc.borrowable);
+        assert!(btc.margin_collateral);
+    }
+
+    #[tokio::test]
+    async fn test_get_borrow_history() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/account/borrow-history")
+            .match_query(Matcher::Exact("currency=BTC&limit=1".into()))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"OK","result":{"nextPageCursor":"2671153%3A1%2C2671153%3A1","list":[{"borrowAmount":"1.06333265702840778","costExemption":"0","freeBorrowedAmount":"0","createdTime":1697439900204,"InterestBearingBorrowSize":"1.06333265702840778","currency":"BTC","unrealisedLoss":"0","hourlyBorrowRate":"0.000001216904","borrowCost":"0.00000129"}]},"retExtInfo":{},"time":1697442206478}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let history = client
+            .get_borrow_history(Some("BTC"), None, None, Some(1), None)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        let record = &history.list[0];
+        assert_eq!(record.created_time, 1697439900204);
+        assert_eq!(record.interest_bearing_borrow_size, "1.06333265702840778");
+        assert_eq!(record.borrow_cost_f64().unwrap(), 0.00000129);
+        assert!(history.next_page_cursor.is_some());
+    }
+
+    fn transaction_log_page(ids: &[&str], next_page_cursor: &str) -> String {
+        let list: Vec<serde_json::Value> = ids
+            .iter()
+            .map(|id| {
+                serde_json::json!({
+                    "id": id,
+                    "symbol": "BTCUSDT",
+                    "category": "linear",
+                    "side": "Sell",
+                    "transactionTime": "1684808511000",
+                    "type": "TRADE",
+                    "qty": "0.001",
+                    "size": "0",
+                    "currency": "USDT",
+                    "tradePrice": "27000",
+                    "funding": "",
+                    "fee": "0.01485",
+                    "cashFlow": "0.1",
+                    "change": "0.08515",
+                    "cashBalance": "1000.08515",
+                    "feeRate": "0.00055",
+                    "bonusChange": "",
+                    "tradeId": "e0cbe81d-0f18-5866-9415-cf319b5dab3b",
+                    "orderId": "fdd92d4c-4b3b-4a63-9d6b-5b8dc2f2c2c1",
+                    "orderLinkId": ""
+                })
+            })
+            .collect();
+        crate::test_support::page_body(list, next_page_cursor)
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_log() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/account/transaction-log")
+            .match_query(Matcher::Exact(
+                "accountType=UNIFIED&category=linear&currency=USDT&startTime=1684800000000&endTime=1684810000000&limit=1"
+                    .into(),
+            ))
+            .with_body(transaction_log_page(&["592324_BTCUSDT_161440249321"], "page-2"))
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let log = client
+            .get_transaction_log(
+                Some("UNIFIED"),
+                Some("linear"),
+                Some("USDT"),
+                Some(1684800000000),
+                Some(1684810000000),
+                Some(1),
+                None,
+            )
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        let entry = &log.list[0];
+        assert_eq!(entry.id, "592324_BTCUSDT_161440249321");
+        assert_eq!(entry.transaction_type, "TRADE");
+        assert_eq!(entry.side, "Sell");
+        assert_eq!(entry.fee, "0.01485");
+        assert_eq!(entry.funding, "");
+        assert_eq!(entry.change_f64().unwrap(), 0.08515);
+        assert_eq!(entry.cash_balance_f64().unwrap(), 1000.08515);
+        assert_eq!(log.next_page_cursor.as_deref(), Some("page-2"));
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_log_paged() {
+        use futures_util::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+        let first = server
+            .mock("GET", "/v5/account/transaction-log")
+            .match_query(Matcher::Exact("currency=USDT&limit=2".into()))
+            .with_body(transaction_log_page(&["1", "2"], "page-2"))
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/v5/account/transaction-log")
+            .match_query(Matcher::Exact("currency=USDT&limit=2&cursor=page-2".into()))
+            .with_body(transaction_log_page(&["3"], ""))
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let ids: Vec<String> = client
+            .get_transaction_log_paged(None, None, Some("USDT"), None, None, Some(2))
+            .map(|entry| entry.unwrap().id)
+            .collect()
+            .await;
+
+        first.assert_async().await;
+        second.assert_async().await;
+        assert_eq!(ids, vec!["1", "2", "3"]);
+    }
 }
@@ -0,0 +1,591 @@
+//! Asset endpoints
+//!
+//! Moves funds between the account types of a single UID, e.g. from `FUND` to
+//! `UNIFIED` before trading, reports per-account-type coin balances, and
+//! handles on-chain deposits and withdrawals.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use rusty_bybit::BybitClient;
+//! use rusty_bybit::types::AccountType;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = BybitClient::testnet()
+//!         .with_credentials("api_key".to_string(), "api_secret".to_string());
+//!     let transfer = client
+//!         .create_internal_transfer(None, "USDT", "100", AccountType::Fund, AccountType::Unified)
+//!         .await
+//!         .unwrap();
+//!     println!("Transfer {} is {}", transfer.transfer_id, transfer.status);
+//! }
+//! ```
+
+use crate::auth::get_current_timestamp_ms;
+use crate::client::BybitClient;
+use crate::error::{BybitError, Result};
+use crate::types::{
+    CoinBalanceResult, CoinInfoList, DepositRecordList, TransferRecordList, TransferResult,
+    WithdrawRecordList, WithdrawableAmount, WithdrawalResult,
+};
+
+impl BybitClient {
+    /// Transfers `amount` of `coin` between two account types of this UID
+    ///
+    /// `transfer_id` must be a UUID and makes the request idempotent; a random
+    /// one is generated when it is `None`. Retry with the same id to avoid
+    /// moving funds twice. The account types accept an
+    /// [`AccountType`](crate::types::AccountType) or its wire string.
+    pub async fn create_internal_transfer(
+        &self,
+        transfer_id: Option<&str>,
+        coin: &str,
+        amount: &str,
+        from_account_type: impl Into<String>,
+        to_account_type: impl Into<String>,
+    ) -> Result<TransferResult> {
+        let transfer_id = transfer_id
+            .map(str::to_string)
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        let body = serde_json::json!({
+            "transferId": transfer_id,
+            "coin": coin,
+            "amount": amount,
+            "fromAccountType": from_account_type.into(),
+            "toAccountType": to_account_type.into(),
+        });
+        self.post("/v5/asset/transfer/inter-transfer", Some(body))
+            .await
+    }
+
+    /// Internal transfer history, newest first
+    ///
+    /// Filter by `coin` or `status` (`SUCCESS`, `PENDING`, `FAILED`); pass the
+    /// previous page's `next_page_cursor` as `cursor` to page through results.
+    pub async fn get_transfer_records(
+        &self,
+        coin: Option<&str>,
+        status: Option<&str>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<TransferRecordList> {
+        let limit = limit.map(|l| l.to_string());
+
+        let mut query = Vec::new();
+        if let Some(c) = coin {
+            query.push(("coin", c));
+        }
+        if let Some(s) = status {
+            query.push(("status", s));
+        }
+        if let Some(l) = &limit {
+            query.push(("limit", l.as_str()));
+        }
+        if let Some(c) = cursor {
+            query.push(("cursor", c));
+        }
+        self.get("/v5/asset/transfer/query-inter-transfer-list", Some(query))
+            .await
+    }
+
+    /// Balance of `coin` in one account type, e.g. `FUND`
+    ///
+    /// Master accounts can pass a sub account's `member_id` to query it instead.
+    pub async fn get_coin_balance(
+        &self,
+        account_type: &str,
+        coin: &str,
+        member_id: Option<&str>,
+    ) -> Result<CoinBalanceResult> {
+        let mut query = vec![("accountType", account_type), ("coin", coin)];
+        if let Some(m) = member_id {
+            query.push(("memberId", m));
+        }
+        self.get("/v5/asset/transfer/query-account-coin-balance", Some(query))
+            .await
+    }
+
+    /// Withdrawal history, newest first
+    ///
+    /// `start_time` and `end_time` are in milliseconds; Bybit limits the range
+    /// to 30 days and defaults to the last 30 days.
+    pub async fn get_withdraw_records(
+        &self,
+        coin: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<WithdrawRecordList> {
+        self.records(
+            "/v5/asset/withdraw/query-record",
+            coin,
+            start_time,
+            end_time,
+            limit,
+            cursor,
+        )
+        .await
+    }
+
+    /// On-chain deposit history, newest first
+    ///
+    /// Takes the same filters as [`get_withdraw_records`](Self::get_withdraw_records).
+    pub async fn get_deposit_records(
+        &self,
+        coin: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<DepositRecordList> {
+        self.records(
+            "/v5/asset/deposit/query-record",
+            coin,
+            start_time,
+            end_time,
+            limit,
+            cursor,
+        )
+        .await
+    }
+
+    async fn records<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        coin: Option<&str>,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<T> {
+        let start_time = start_time.map(|t| t.to_string());
+        let end_time = end_time.map(|t| t.to_string());
+        let limit = limit.map(|l| l.to_string());
+
+        let mut query = Vec::new();
+        if let Some(c) = coin {
+            query.push(("coin", c));
+        }
+        if let Some(s) = &start_time {
+            query.push(("startTime", s.as_str()));
+        }
+        if let Some(e) = &end_time {
+            query.push(("endTime", e.as_str()));
+        }
+        if let Some(l) = &limit {
+            query.push(("limit", l.as_str()));
+        }
+        if let Some(c) = cursor {
+            query.push(("cursor", c));
+        }
+        self.get(path, Some(query)).await
+    }
+
+    /// Chains, fees and minimum amounts for depositing and withdrawing `coin`,
+    /// or for every coin when `None`
+    pub async fn get_coin_info(&self, coin: Option<&str>) -> Result<CoinInfoList> {
+        let mut query = Vec::new();
+        if let Some(c) = coin {
+            query.push(("coin", c));
+        }
+        self.get("/v5/asset/coin/query-info", Some(query)).await
+    }
+
+    /// How much of `coin` can be withdrawn right now, per account type
+    pub async fn get_withdrawable_amount(&self, coin: &str) -> Result<WithdrawableAmount> {
+        self.get(
+            "/v5/asset/withdraw/withdrawable-amount",
+            Some(vec![("coin", coin)]),
+        )
+        .await
+    }
+
+    /// Withdraws `amount` of `coin` to `address` on `chain`
+    ///
+    /// Valid chains, fees and minimum amounts come from
+    /// [`get_coin_info`](Self::get_coin_info).
+    ///
+    /// `tag` is the memo required by some chains, and `account_type` selects
+    /// the account to withdraw from (`FUND` when `None`). Fails with
+    /// `AuthenticationError` before sending anything if the client has no
+    /// credentials. Rejections such as a missing 2FA setup or an address that
+    /// is not whitelisted are returned as `ApiError` with Bybit's `retCode`
+    /// and `retMsg`.
+    ///
+    /// `request_id` lets Bybit reject a repeated withdrawal as a duplicate; a
+    /// random one is generated when `None`, so pass your own to retry safely
+    /// after an ambiguous failure. The request itself is never re-sent after a
+    /// timeout or server error, even with [`with_retry`](Self::with_retry).
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_withdrawal(
+        &self,
+        coin: &str,
+        chain: &str,
+        address: &str,
+        amount: &str,
+        tag: Option<&str>,
+        account_type: Option<&str>,
+        request_id: Option<&str>,
+    ) -> Result<WithdrawalResult> {
+        if !self.has_credentials() {
+            return Err(BybitError::AuthenticationError(
+                "withdrawals require API credentials".to_string(),
+            ));
+        }
+
+        let request_id = request_id
+            .map(str::to_string)
+            .unwrap_or_else(|| uuid::Uuid::new_v4().simple().to_string());
+        let mut body = serde_json::json!({
+            "coin": coin,
+            "chain": chain,
+            "address": address,
+            "amount": amount,
+            "timestamp": get_current_timestamp_ms() + self.time_offset_ms(),
+            "requestId": request_id,
+        });
+        if let Some(t) = tag {
+            body["tag"] = t.into();
+        }
+        if let Some(a) = account_type {
+            body["accountType"] = a.into();
+        }
+        self.post_without_resend("/v5/asset/withdraw/create", body)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AccountType;
+    use mockito::Matcher;
+
+    #[tokio::test]
+    async fn test_create_internal_transfer() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/asset/transfer/inter-transfer")
+            .match_body(Matcher::Json(serde_json::json!({
+                "transferId": "42c0cfb0-6bca-c242-bc76-4e6df6cbcb16",
+                "coin": "BTC",
+                "amount": "0.05",
+                "fromAccountType": "UNIFIED",
+                "toAccountType": "CONTRACT",
+            })))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"success","result":{"transferId":"42c0cfb0-6bca-c242-bc76-4e6df6cbcb16","status":"SUCCESS"},"retExtInfo":{},"time":1670986690556}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let transfer = client
+            .create_internal_transfer(
+                Some("42c0cfb0-6bca-c242-bc76-4e6df6cbcb16"),
+                "BTC",
+                "0.05",
+                "UNIFIED",
+                "CONTRACT",
+            )
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(transfer.transfer_id, "42c0cfb0-6bca-c242-bc76-4e6df6cbcb16");
+        assert_eq!(transfer.status, "SUCCESS");
+    }
+
+    #[tokio::test]
+    async fn test_create_internal_transfer_generates_id() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/asset/transfer/inter-transfer")
+            .match_body(Matcher::Regex(
+                r#""transferId":"[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[0-9a-f]{4}-[0-9a-f]{12}""#
+                    .to_string(),
+            ))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"success","result":{"transferId":"generated","status":"PENDING"},"retExtInfo":{},"time":1670986690556}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        client
+            .create_internal_transfer(None, "USDT", "100", AccountType::Fund, AccountType::Unified)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_transfer_records() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/asset/transfer/query-inter-transfer-list")
+            .match_query(Matcher::Exact("coin=USDT&limit=1".into()))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"success","result":{"list":[{"transferId":"selfTransfer_a1091cc7-9364-4b74-8de1-18f02c6f2d5c","coin":"USDT","amount":"5000","fromAccountType":"SPOT","toAccountType":"UNIFIED","timestamp":"1667283263000","status":"SUCCESS"}],"nextPageCursor":"eyJtaW5JRCI6MTM1ODQ2OCwibWF4SUQiOjEzNTg0Njh9"},"retExtInfo":{},"time":1670988271677}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let records = client
+            .get_transfer_records(Some("USDT"), None, Some(1), None)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        let record = &records.list[0];
+        assert_eq!(record.from_account_type, "SPOT");
+        assert_eq!(record.to_account_type, "UNIFIED");
+        assert_eq!(record.amount_f64().unwrap(), 5000.0);
+        assert!(records.next_page_cursor.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_coin_balance() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/asset/transfer/query-account-coin-balance")
+            .match_query(Matcher::Exact("accountType=FUND&coin=USDT".into()))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"success","result":{"accountType":"FUND","bizType":1,"accountId":"1631385","memberId":"1631373","balance":{"coin":"USDT","walletBalance":"11999","transferBalance":"11999","bonus":"0","transferSafeAmount":"","ltvTransferSafeAmount":""}},"retExtInfo":{},"time":1684894492862}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let result = client.get_coin_balance("FUND", "USDT", None).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(result.account_type, "FUND");
+        assert_eq!(result.balance.coin, "USDT");
+        assert_eq!(result.balance.wallet_balance_f64().unwrap(), 11999.0);
+        assert_eq!(result.balance.transfer_balance, "11999");
+        assert_eq!(result.balance.bonus, "0");
+    }
+
+    #[tokio::test]
+    async fn test_get_withdraw_records() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/asset/withdraw/query-record")
+            .match_query(Matcher::Exact("coin=USDT&limit=1".into()))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"success","result":{"rows":[{"coin":"USDT","chain":"ETH","amount":"8","txID":"","status":"SecurityCheck","toAddress":"0x99ced129603abc771c0dabe935c326ff6c86645d","tag":"","withdrawFee":"3","createTime":"1670922217000","updateTime":"1670922217000","withdrawId":"9976","withdrawType":0}],"nextPageCursor":"eyJtaW5JRCI6OTk3NiwibWF4SUQiOjk5NzZ9"},"retExtInfo":{},"time":1670988051626}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let records = client
+            .get_withdraw_records(Some("USDT"), None, None, Some(1), None)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        let record = &records.rows[0];
+        assert_eq!(record.withdraw_id, "9976");
+        assert_eq!(record.status, "SecurityCheck");
+        assert_eq!(record.amount_f64().unwrap(), 8.0);
+        assert_eq!(record.withdraw_fee_f64().unwrap(), 3.0);
+        assert!(records.next_page_cursor.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_deposit_records() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/asset/deposit/query-record")
+            .match_query(Matcher::Exact(
+                "coin=USDT&startTime=1665000000000&endTime=1667000000000".into(),
+            ))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"success","result":{"rows":[{"coin":"USDT","chain":"ETH","amount":"10000","txID":"skip-notification-scene-test-amount-202212270944-533285-USDT","status":3,"toAddress":"test-amount-address","tag":"","depositFee":"","successAt":"1672134274000","confirmations":"10000","txIndex":"","blockHash":"","batchReleaseLimit":"-1","depositType":"0"}],"nextPageCursor":"eyJtaW5JRCI6MTA0NjA0MywibWF4SUQiOjEwNDYwNDN9"},"retExtInfo":{},"time":1672191992512}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let records = client
+            .get_deposit_records(
+                Some("USDT"),
+                Some(1665000000000),
+                Some(1667000000000),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        let record = &records.rows[0];
+        assert_eq!(record.status, 3);
+        assert_eq!(record.success_at, "1672134274000");
+        assert_eq!(record.amount_f64().unwrap(), 10000.0);
+    }
+
+    #[tokio::test]
+    async fn test_create_withdrawal() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/asset/withdraw/create")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::PartialJson(serde_json::json!({
+                    "coin": "USDT",
+                    "chain": "ETH",
+                    "address": "0x99ced129603abc771c0dabe935c326ff6c86645d",
+                    "amount": "24",
+                    "accountType": "FUND",
+                })),
+                Matcher::Regex(r#""timestamp":\d{13}"#.to_string()),
+                Matcher::Regex(r#""requestId":"[0-9a-f]{32}""#.to_string()),
+            ]))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"success","result":{"id":"10195"},"retExtInfo":{},"time":1672196571239}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let withdrawal = client
+            .create_withdrawal(
+                "USDT",
+                "ETH",
+                "0x99ced129603abc771c0dabe935c326ff6c86645d",
+                "24",
+                None,
+                Some("FUND"),
+                None,
+            )
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(withdrawal.id, "10195");
+    }
+
+    #[tokio::test]
+    async fn test_create_withdrawal_is_not_resent_after_server_error() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/asset/withdraw/create")
+            .match_body(Matcher::PartialJson(
+                serde_json::json!({ "requestId": "withdraw-1" }),
+            ))
+            .with_status(502)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url())
+            .with_credentials("key".into(), "secret".into())
+            .with_retry(crate::RetryConfig {
+                max_attempts: 3,
+                base_delay: std::time::Duration::from_millis(1),
+                max_delay: std::time::Duration::from_millis(5),
+            });
+        let err = client
+            .create_withdrawal("USDT", "ETH", "0xabc", "24", None, None, Some("withdraw-1"))
+            .await
+            .unwrap_err();
+
+        mock.assert_async().await;
+        assert!(matches!(err, BybitError::HttpStatus { status: 502, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_create_withdrawal_requires_credentials() {
+        let client = BybitClient::new("http://127.0.0.1:1".to_string());
+        let result = client
+            .create_withdrawal("USDT", "ETH", "0xabc", "24", None, None, None)
+            .await;
+
+        assert!(matches!(result, Err(BybitError::AuthenticationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_withdrawal_surfaces_api_error() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/v5/asset/withdraw/create")
+            .with_body(
+                r#"{"retCode":131002,"retMsg":"Withdraw address chain or destination tag are not equal","result":{},"retExtInfo":{},"time":1672196571239}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let err = client
+            .create_withdrawal("USDT", "ETH", "0xabc", "24", None, None, None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.ret_code(), Some(131002));
+    }
+
+    #[tokio::test]
+    async fn test_get_coin_info() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/asset/coin/query-info")
+            .match_query(Matcher::Exact("coin=MNT".into()))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"success","result":{"rows":[{"name":"MNT","coin":"MNT","remainAmount":"10000000","chains":[{"chainType":"Ethereum","confirmation":"6","withdrawFee":"3","depositMin":"0","withdrawMin":"3","chain":"ETH","chainDeposit":"1","chainWithdraw":"1","minAccuracy":"8","withdrawPercentageFee":"0"},{"chainType":"Mantle Network","confirmation":"100","withdrawFee":"0","depositMin":"0","withdrawMin":"10","chain":"MANTLE","chainDeposit":"1","chainWithdraw":"1","minAccuracy":"8","withdrawPercentageFee":"0"}]}]},"retExtInfo":{},"time":1736395486989}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let info = client.get_coin_info(Some("MNT")).await.unwrap();
+
+        mock.assert_async().await;
+        let coin = &info.rows[0];
+        assert_eq!(coin.chains.len(), 2);
+        let eth = coin.chain("ETH").unwrap();
+        assert_eq!(eth.chain_type, "Ethereum");
+        assert_eq!(eth.confirmations, "6");
+        assert_eq!(eth.withdraw_fee_f64().unwrap(), 3.0);
+        assert_eq!(
+            coin.chain("MANTLE").unwrap().min_withdraw_f64().unwrap(),
+            10.0
+        );
+        assert!(coin.chain("TRX").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_withdrawable_amount() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/asset/withdraw/withdrawable-amount")
+            .match_query(Matcher::Exact("coin=USDT".into()))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"success","result":{"limitAmountUsd":"721.0000","withdrawableAmount":{"SPOT":{"coin":"USDT","withdrawableAmount":"0","availableBalance":"0"},"FUND":{"coin":"USDT","withdrawableAmount":"155.9982","availableBalance":"155.9982"}}},"retExtInfo":{},"time":1701298584433}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let amount = client.get_withdrawable_amount("USDT").await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(amount.limit_amount_usd_f64().unwrap(), 721.0);
+        assert_eq!(amount.withdrawable_amount.len(), 2);
+        assert_eq!(
+            amount.withdrawable_amount["SPOT"]
+                .withdrawable_amount_f64()
+                .unwrap(),
+            0.0
+        );
+        let fund = &amount.withdrawable_amount["FUND"];
+        assert_eq!(fund.coin, "USDT");
+        assert_eq!(fund.withdrawable_amount_f64().unwrap(), 155.9982);
+        assert_eq!(fund.available_balance, "155.9982");
+    }
+}
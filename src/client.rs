@@ -21,30 +21,109 @@
 //! }
 //! ```
 
-use crate::auth::{Credentials, generate_signature, get_current_timestamp_ms};
-use crate::error::{BybitError, Result};
-use crate::types::ApiResponse;
+use crate::auth::{Credentials, RsaPrivateKey, get_current_timestamp_ms};
+use crate::error::{ApiErrorKind, BybitError, Result};
+use crate::logging::RequestLogger;
+use crate::rate_limit::RateLimiter;
+use crate::types::{ApiResponse, EmptyResult, Meta, RateLimitStatus, ServerTime};
 use reqwest::header::{HeaderMap, HeaderValue};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-const RECV_WINDOW: u64 = 5000;
+const TESTNET_URL: &str = "https://api-testnet.bybit.com";
+const MAINNET_URL: &str = "https://api.bybit.com";
+const DEMO_URL: &str = "https://api-demo.bybit.com";
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_RECV_WINDOW: u64 = 5000;
+const MAX_RECV_WINDOW: u64 = 60_000;
+
+/// Retry policy for transient failures
+///
+/// Connection errors, timeouts, HTTP 429 and 5xx responses, and rate-limit or
+/// server-side API errors (see
+/// [`ApiErrorKind::is_retryable`](crate::error::ApiErrorKind::is_retryable)) are
+/// retried with jittered exponential backoff; all other errors fail immediately.
+/// Note that a timed-out order request may already have reached the exchange, so
+/// set `order_link_id` on orders placed through a retrying client.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Total number of attempts, including the first one
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Default backoff with up to `max_retries` retries after the first attempt
+    pub fn with_max_retries(max_retries: u32) -> Self {
+        Self {
+            max_attempts: max_retries.saturating_add(1),
+            ..Self::default()
+        }
+    }
+
+    /// Delay before retry number `attempt` (starting at 1): the exponential
+    /// backoff capped at `max_delay`, jittered down by up to half.
+    fn delay(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+        let jitter = RandomState::new().build_hasher().finish() % 1000;
+        backoff / 2 + (backoff / 2).mul_f64(jitter as f64 / 1000.0)
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct BybitClient {
     pub base_url: String,
     http_client: reqwest::Client,
     credentials: Option<Credentials>,
+    recv_window: u64,
+    http: HttpSettings,
+    retry: Option<RetryConfig>,
+    rate_limit: Arc<Mutex<Option<RateLimitStatus>>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    endpoint_rate_limiters: Vec<(String, Arc<RateLimiter>)>,
+    logger: Option<Arc<dyn RequestLogger>>,
+    default_headers: HeaderMap,
+    time_offset_ms: Arc<AtomicI64>,
+    auto_time_sync: bool,
+    time_synced: Arc<AtomicBool>,
+    auto_order_link_id: bool,
 }
 
 impl BybitClient {
     pub fn new(base_url: String) -> Self {
-        let http_client = reqwest::Client::builder()
-            .build()
-            .expect("Failed to create HTTP client");
-
         Self {
             base_url,
-            http_client,
+            http_client: HttpSettings::default().build(),
             credentials: None,
+            recv_window: DEFAULT_RECV_WINDOW,
+            http: HttpSettings::default(),
+            retry: None,
+            rate_limit: Arc::new(Mutex::new(None)),
+            rate_limiter: None,
+            endpoint_rate_limiters: Vec::new(),
+            logger: None,
+            default_headers: HeaderMap::new(),
+            time_offset_ms: Arc::new(AtomicI64::new(0)),
+            auto_time_sync: false,
+            time_synced: Arc::new(AtomicBool::new(false)),
+            auto_order_link_id: false,
         }
     }
 
@@ -53,21 +132,368 @@ impl BybitClient {
         self
     }
 
+    /// Signs requests with RSA-SHA256 for a self-generated RSA API key instead of HMAC
+    pub fn with_rsa_credentials(mut self, api_key: String, private_key: RsaPrivateKey) -> Self {
+        self.credentials = Some(Credentials::new_rsa(api_key, private_key));
+        self
+    }
+
+    /// Like [`with_rsa_credentials`](Self::with_rsa_credentials), reading the
+    /// private key from a PKCS#8 or PKCS#1 PEM string
+    pub fn with_rsa_pem(mut self, api_key: String, pem: &str) -> Result<Self> {
+        self.credentials = Some(Credentials::from_rsa_pem(api_key, pem)?);
+        Ok(self)
+    }
+
+    /// Sets how long (in milliseconds) a signed request stays valid after its timestamp.
+    ///
+    /// Defaults to 5000. Raise it on high-latency connections that hit `10002`
+    /// timestamp errors; values of 0 or above 60000 are rejected.
+    pub fn with_recv_window(mut self, recv_window: u64) -> Result<Self> {
+        if recv_window == 0 || recv_window > MAX_RECV_WINDOW {
+            return Err(BybitError::InvalidParameter(format!(
+                "recv_window must be between 1 and {} ms, got {}",
+                MAX_RECV_WINDOW, recv_window
+            )));
+        }
+        self.recv_window = recv_window;
+        Ok(self)
+    }
+
+    /// Sets the total time allowed for a request, from connecting until the body is read.
+    ///
+    /// Defaults to 10 seconds. Expired requests fail with [`BybitError::Timeout`].
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.http.timeout = timeout;
+        self.http_client = self.http.build();
+        self
+    }
+
+    /// Caps the idle connections kept open per host for reuse
+    ///
+    /// Defaults to reqwest's (unbounded). Lower it to bound open sockets, or
+    /// keep it above the usual burst size so order bursts reuse established
+    /// TLS connections.
+    pub fn with_pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.http.pool_max_idle_per_host = Some(max_idle);
+        self.http_client = self.http.build();
+        self
+    }
+
+    /// Closes pooled connections after they have been idle for `timeout`
+    ///
+    /// Defaults to reqwest's 90 seconds; `None` keeps idle connections open
+    /// indefinitely.
+    pub fn with_pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.http.pool_idle_timeout = Some(timeout);
+        self.http_client = self.http.build();
+        self
+    }
+
+    /// Sends all requests through `proxy`, e.g. `reqwest::Proxy::all("socks5://10.0.0.1:1080")`
+    pub fn with_proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.http.proxy = ProxySetting::Custom(Box::new(proxy));
+        self.http_client = self.http.build();
+        self
+    }
+
+    /// Like [`with_proxy`](Self::with_proxy), taking an `http://`, `https://`
+    /// or `socks5://` proxy URL
+    pub fn with_proxy_url(self, url: &str) -> Result<Self> {
+        let proxy = reqwest::Proxy::all(url).map_err(|e| {
+            BybitError::InvalidParameter(format!("invalid proxy URL '{}': {}", url, e))
+        })?;
+        Ok(self.with_proxy(proxy))
+    }
+
+    /// Connects directly, ignoring `HTTP_PROXY`/`HTTPS_PROXY` and any proxy
+    /// set earlier
+    pub fn with_no_proxy(mut self) -> Self {
+        self.http.proxy = ProxySetting::Disabled;
+        self.http_client = self.http.build();
+        self
+    }
+
+    /// Retries transient failures according to `retry`; the last error is returned
+    /// once all attempts are used.
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Throttles outgoing requests to `requests_per_second`, allowing bursts of
+    /// up to one second's worth. Clones of a client share the same budget.
+    pub fn with_rate_limit(mut self, requests_per_second: u32) -> Self {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(requests_per_second)));
+        self
+    }
+
+    /// Gives requests whose path starts with `path_prefix` their own budget of
+    /// `requests_per_second`, e.g. `/v5/order` for Bybit's order limits
+    ///
+    /// Bybit limits each endpoint group separately, so one busy group shouldn't
+    /// hold up the others. When several prefixes match, the longest one is used;
+    /// a limit from [`with_rate_limit`](Self::with_rate_limit) still applies on
+    /// top. Setting the same prefix again replaces its budget.
+    pub fn with_endpoint_rate_limit(
+        mut self,
+        path_prefix: impl Into<String>,
+        requests_per_second: u32,
+    ) -> Self {
+        let path_prefix = path_prefix.into();
+        let limiter = Arc::new(RateLimiter::new(requests_per_second));
+        self.endpoint_rate_limiters
+            .retain(|(prefix, _)| *prefix != path_prefix);
+        self.endpoint_rate_limiters.push((path_prefix, limiter));
+        self
+    }
+
+    /// Sends `user_agent` as the `User-Agent` of every request
+    pub fn with_user_agent(self, user_agent: impl Into<String>) -> Result<Self> {
+        self.with_default_header(reqwest::header::USER_AGENT.as_str(), &user_agent.into())
+    }
+
+    /// Adds a header to every request, e.g. one required by a gateway
+    ///
+    /// Setting the same name again replaces the value. `X-BAPI-*` names are
+    /// reserved for authentication and rejected, as are invalid names or values.
+    pub fn with_default_header(mut self, name: &str, value: &str) -> Result<Self> {
+        if name.to_ascii_lowercase().starts_with("x-bapi-") {
+            return Err(BybitError::InvalidParameter(format!(
+                "header '{}' is reserved for authentication",
+                name
+            )));
+        }
+        let name = reqwest::header::HeaderName::try_from(name).map_err(|e| {
+            BybitError::InvalidParameter(format!("invalid header name '{}': {}", name, e))
+        })?;
+        let value = HeaderValue::try_from(value).map_err(|e| {
+            BybitError::InvalidParameter(format!("invalid value for header '{}': {}", name, e))
+        })?;
+        self.default_headers.insert(name, value);
+        Ok(self)
+    }
+
+    /// Passes every request and response to `logger`; see [`crate::logging`]
+    pub fn with_logger(mut self, logger: Arc<dyn RequestLogger>) -> Self {
+        self.logger = Some(logger);
+        self
+    }
+
+    /// Gives orders placed with [`create_order`](Self::create_order) without an
+    /// `order_link_id` a random UUID one
+    ///
+    /// The id is generated once per call and reused by every retry, so a create
+    /// retried after a timeout is rejected by Bybit as a duplicate instead of
+    /// placing a second order. It is part of the logged request body and of the
+    /// returned [`CreateOrderResponse`](crate::types::CreateOrderResponse).
+    pub fn with_auto_order_link_id(mut self, enabled: bool) -> Self {
+        self.auto_order_link_id = enabled;
+        self
+    }
+
+    pub(crate) fn auto_order_link_id(&self) -> bool {
+        self.auto_order_link_id
+    }
+
+    /// Requests that can be sent right now without waiting, or `None` if no
+    /// rate limit is configured
+    pub fn available_permits(&self) -> Option<u32> {
+        self.rate_limiter.as_ref().map(|l| l.available_permits())
+    }
+
+    /// Rate-limit headers of the most recent response that carried them
+    ///
+    /// Bybit limits are tracked per endpoint, so this reflects whichever endpoint
+    /// was called last. Clones of a client share the same status.
+    pub fn last_rate_limit(&self) -> Option<RateLimitStatus> {
+        *self.rate_limit.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Calls [`sync_time`](Self::sync_time) before the first authenticated
+    /// request, so a drifted local clock doesn't cause `10002` timestamp errors
+    pub fn with_auto_time_sync(mut self) -> Self {
+        self.auto_time_sync = true;
+        self
+    }
+
+    /// Offset in milliseconds added to the local clock when signing requests
+    ///
+    /// Zero until [`sync_time`](Self::sync_time) has run. Clones of a client
+    /// share the same offset.
+    pub fn time_offset_ms(&self) -> i64 {
+        self.time_offset_ms.load(Ordering::Relaxed)
+    }
+
+    /// Measures the offset between the local clock and Bybit's server time and
+    /// applies it to the timestamps of signed requests
+    ///
+    /// The server time is compared against the midpoint of the round-trip, so
+    /// network latency doesn't skew the result. Returns the new offset.
+    pub async fn sync_time(&self) -> Result<i64> {
+        let local_send_ms = get_current_timestamp_ms();
+        let response: ApiResponse<ServerTime> = self
+            .send_request(&reqwest::Method::GET, "/v5/market/time", None, None)
+            .await?;
+        let local_receive_ms = get_current_timestamp_ms();
+
+        let server_time_ms = response
+            .result
+            .time_nano
+            .parse::<i64>()
+            .map(|nanos| nanos / 1_000_000)
+            .unwrap_or(response.time);
+        let offset = server_time_ms - (local_send_ms + (local_receive_ms - local_send_ms) / 2);
+
+        self.time_offset_ms.store(offset, Ordering::Relaxed);
+        self.time_synced.store(true, Ordering::Relaxed);
+        Ok(offset)
+    }
+
     pub fn testnet() -> Self {
-        Self::new("https://api-testnet.bybit.com".to_string())
+        Self::new(TESTNET_URL.to_string())
     }
 
     pub fn mainnet() -> Self {
-        Self::new("https://api.bybit.com".to_string())
+        Self::new(MAINNET_URL.to_string())
+    }
+
+    /// Client for Bybit's demo trading environment, which is separate from
+    /// testnet and needs API keys created in demo trading mode
+    pub fn demo() -> Self {
+        Self::new(DEMO_URL.to_string())
+    }
+
+    /// Points the client at another host, e.g. a regional domain such as
+    /// `https://api.bybit.nl`
+    ///
+    /// The URL must be an `https` URL; anything else is rejected with
+    /// `InvalidParameter`.
+    pub fn with_base_url(mut self, base_url: impl AsRef<str>) -> Result<Self> {
+        self.base_url = validate_base_url(base_url.as_ref())?;
+        Ok(self)
     }
 
+    /// Builds a client from `BYBIT_BASE_URL`, `BYBIT_API_KEY` and `BYBIT_API_SECRET`
+    ///
+    /// Uses testnet when `BYBIT_BASE_URL` is unset, and sets credentials only
+    /// when both the key and the secret are present. A base URL that is not a
+    /// valid `https` URL is rejected with `InvalidParameter`. An optional
+    /// `BYBIT_RECV_WINDOW` is applied through [`with_recv_window`](Self::with_recv_window).
+    pub fn from_env() -> Result<Self> {
+        let base_url = match std::env::var("BYBIT_BASE_URL") {
+            Ok(url) => validate_base_url(&url)?,
+            Err(_) => TESTNET_URL.to_string(),
+        };
+
+        let mut client = Self::new(base_url);
+        if let Ok(recv_window) = std::env::var("BYBIT_RECV_WINDOW") {
+            client = client.with_recv_window(parse_recv_window(&recv_window)?)?;
+        }
+
+        match (
+            std::env::var("BYBIT_API_KEY"),
+            std::env::var("BYBIT_API_SECRET"),
+        ) {
+            (Ok(api_key), Ok(api_secret)) => Ok(client.with_credentials(api_key, api_secret)),
+            _ => Ok(client),
+        }
+    }
+
+    /// Sends a request with retries; with the `tracing` feature, wrapped in a
+    /// `bybit_request` span recording the method, path, `ret_code` and latency
     async fn request<T: serde::de::DeserializeOwned>(
         &self,
         method: &reqwest::Method,
         path: &str,
         query: Option<&[(&str, &str)]>,
         body: Option<&serde_json::Value>,
-    ) -> Result<T> {
+        retry_if: fn(&BybitError) -> bool,
+    ) -> Result<ApiResponse<T>> {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+
+            let span = tracing::debug_span!(
+                "bybit_request",
+                method = %method,
+                path,
+                ret_code = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+            );
+            let started = Instant::now();
+            let result = self
+                .request_with_retry(method, path, query, body, retry_if)
+                .instrument(span.clone())
+                .await;
+
+            span.record("latency_ms", started.elapsed().as_millis() as u64);
+            let ret_code = match &result {
+                Ok(response) => Some(response.ret_code),
+                Err(e) => e.ret_code(),
+            };
+            if let Some(ret_code) = ret_code {
+                span.record("ret_code", ret_code);
+            }
+            span.in_scope(|| match &result {
+                Ok(_) => tracing::debug!("request completed"),
+                Err(e) => tracing::debug!(error = %e, "request failed"),
+            });
+            result
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.request_with_retry(method, path, query, body, retry_if)
+            .await
+    }
+
+    async fn request_with_retry<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &reqwest::Method,
+        path: &str,
+        query: Option<&[(&str, &str)]>,
+        body: Option<&serde_json::Value>,
+        retry_if: fn(&BybitError) -> bool,
+    ) -> Result<ApiResponse<T>> {
+        if self.auto_time_sync
+            && self.credentials.is_some()
+            && !self.time_synced.load(Ordering::Relaxed)
+        {
+            self.sync_time().await?;
+        }
+
+        let max_attempts = self.retry.as_ref().map_or(1, |r| r.max_attempts.max(1));
+        let mut attempt = 1;
+
+        loop {
+            match self.send_request(method, path, query, body).await {
+                Err(e) if attempt < max_attempts && retry_if(&e) => {
+                    if let Some(retry) = &self.retry {
+                        tokio::time::sleep(retry.delay(attempt)).await;
+                    }
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn send_request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &reqwest::Method,
+        path: &str,
+        query: Option<&[(&str, &str)]>,
+        body: Option<&serde_json::Value>,
+    ) -> Result<ApiResponse<T>> {
+        // Throttle before signing, so time spent waiting for a token doesn't
+        // age the timestamp and eat into `recv_window`
+        if let Some(limiter) = self.endpoint_rate_limiter(path) {
+            limiter.acquire().await;
+        }
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire().await;
+        }
+
         let url = format!("{}{}", self.base_url, path);
 
         let mut builder = self.http_client.request(method.clone(), &url);
@@ -76,8 +502,19 @@ impl BybitClient {
             builder = builder.query(q);
         }
 
+        if !self.default_headers.is_empty() {
+            builder = builder.headers(self.default_headers.clone());
+        }
+
         if let Some(creds) = &self.credentials {
             let headers = self.build_auth_headers(method, path, query, body, creds)?;
+            #[cfg(feature = "tracing")]
+            tracing::trace!(
+                api_key = %redact(&creds.api_key),
+                timestamp = ?headers.get("X-BAPI-TIMESTAMP"),
+                recv_window = self.recv_window,
+                "signed request"
+            );
             builder = builder.headers(headers);
         }
 
@@ -85,19 +522,69 @@ impl BybitClient {
             builder = builder.json(b);
         }
 
+        if let Some(logger) = &self.logger {
+            let url = match query {
+                Some(q) if !q.is_empty() => {
+                    format!(
+                        "{}?{}",
+                        url,
+                        serde_urlencoded::to_string(q).unwrap_or_default()
+                    )
+                }
+                _ => url,
+            };
+            let body = body.map(|b| b.to_string());
+            logger.on_request(method.as_str(), &url, body.as_deref());
+        }
+
         let response = builder.send().await?;
+        let rate_limit = parse_rate_limit_headers(response.headers());
+        if let Some(status) = rate_limit {
+            *self.rate_limit.lock().unwrap_or_else(|e| e.into_inner()) = Some(status);
+        }
+        let status = response.status();
         let response_text = response.text().await?;
+        if let Some(logger) = &self.logger {
+            logger.on_response(status.as_u16(), &response_text);
+        }
+
+        // Maintenance and CDN error pages are HTML, not a Bybit JSON envelope
+        if !status.is_success() {
+            return Err(BybitError::HttpStatus {
+                status: status.as_u16(),
+                body: response_text,
+            });
+        }
 
-        let api_response: ApiResponse<T> = serde_json::from_str(&response_text)?;
+        // Error responses carry an empty `result`, so check the code before
+        // deserializing into the endpoint type.
+        let api_response: ApiResponse<serde_json::Value> = serde_json::from_str(&response_text)?;
 
         if api_response.ret_code != 0 {
+            // An exhausted endpoint budget tells us when to come back
+            if let Some(rate_limit) = rate_limit.filter(|r| {
+                r.remaining == 0
+                    && ApiErrorKind::from_code(api_response.ret_code) == ApiErrorKind::RateLimit
+            }) {
+                let now = get_current_timestamp_ms().max(0) as u64;
+                return Err(BybitError::RateLimitExceeded {
+                    limit_type: path.to_string(),
+                    limit_reset_ms: Some(rate_limit.reset_timestamp_ms.saturating_sub(now)),
+                });
+            }
             return Err(BybitError::ApiError {
                 ret_code: api_response.ret_code,
                 ret_msg: api_response.ret_msg,
             });
         }
 
-        Ok(api_response.result)
+        Ok(ApiResponse {
+            ret_code: api_response.ret_code,
+            ret_msg: api_response.ret_msg,
+            result: serde_json::from_value(api_response.result)?,
+            ret_ext_info: api_response.ret_ext_info,
+            time: api_response.time,
+        })
     }
 
     pub(crate) async fn get<T: serde::de::DeserializeOwned>(
@@ -105,8 +592,15 @@ impl BybitClient {
         path: &str,
         query: Option<Vec<(&str, &str)>>,
     ) -> Result<T> {
-        self.request(&reqwest::Method::GET, path, query.as_deref(), None)
-            .await
+        self.request(
+            &reqwest::Method::GET,
+            path,
+            query.as_deref(),
+            None,
+            BybitError::is_retryable,
+        )
+        .await
+        .map(|response| response.result)
     }
 
     pub(crate) async fn post<T: serde::de::DeserializeOwned>(
@@ -114,8 +608,99 @@ impl BybitClient {
         path: &str,
         body: Option<serde_json::Value>,
     ) -> Result<T> {
-        self.request(&reqwest::Method::POST, path, None, body.as_ref())
-            .await
+        self.request(
+            &reqwest::Method::POST,
+            path,
+            None,
+            body.as_ref(),
+            BybitError::is_retryable,
+        )
+        .await
+        .map(|response| response.result)
+    }
+
+    /// Like `post`, for requests that must not be executed twice, such as
+    /// withdrawals: only connection failures are retried, since then nothing
+    /// reached Bybit, while a timeout or 5xx may follow a request that did
+    pub(crate) async fn post_without_resend<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        body: serde_json::Value,
+    ) -> Result<T> {
+        self.request(
+            &reqwest::Method::POST,
+            path,
+            None,
+            Some(&body),
+            |e| matches!(e, BybitError::RequestError(e) if e.is_connect()),
+        )
+        .await
+        .map(|response| response.result)
+    }
+
+    /// Like `post`, for endpoints whose `result` carries no data
+    pub(crate) async fn post_empty(&self, path: &str, body: serde_json::Value) -> Result<()> {
+        self.post::<EmptyResult>(path, Some(body)).await.map(|_| ())
+    }
+
+    /// Like `get`, but also reports the round-trip time and clock skew
+    pub(crate) async fn get_timed<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        query: Option<Vec<(&str, &str)>>,
+    ) -> Result<(T, Meta)> {
+        let local_send_ms = get_current_timestamp_ms();
+        let started = Instant::now();
+        let response = self
+            .request(
+                &reqwest::Method::GET,
+                path,
+                query.as_deref(),
+                None,
+                BybitError::is_retryable,
+            )
+            .await?;
+        let latency_ms = started.elapsed().as_millis() as u64;
+        let local_receive_ms = get_current_timestamp_ms();
+
+        let meta = Meta {
+            latency_ms,
+            server_time_ms: response.time,
+            local_send_ms,
+            local_receive_ms,
+            clock_skew_ms: response.time - local_receive_ms,
+        };
+        Ok((response.result, meta))
+    }
+
+    /// Like `post`, but keeps the whole response so batch endpoints can read
+    /// the per-item results in `retExtInfo`
+    pub(crate) async fn post_response<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        body: serde_json::Value,
+    ) -> Result<ApiResponse<T>> {
+        self.request(
+            &reqwest::Method::POST,
+            path,
+            None,
+            Some(&body),
+            BybitError::is_retryable,
+        )
+        .await
+    }
+
+    /// Bucket of the longest configured prefix of `path`
+    fn endpoint_rate_limiter(&self, path: &str) -> Option<&RateLimiter> {
+        self.endpoint_rate_limiters
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, limiter)| limiter.as_ref())
+    }
+
+    pub(crate) fn has_credentials(&self) -> bool {
+        self.credentials.is_some()
     }
 
     fn build_auth_headers(
@@ -126,7 +711,7 @@ impl BybitClient {
         body: Option<&serde_json::Value>,
         credentials: &Credentials,
     ) -> Result<HeaderMap> {
-        let timestamp = get_current_timestamp_ms();
+        let timestamp = get_current_timestamp_ms() + self.time_offset_ms();
 
         let payload = match *method {
             reqwest::Method::GET => {
@@ -146,13 +731,7 @@ impl BybitClient {
             _ => String::new(),
         };
 
-        let signature = generate_signature(
-            timestamp,
-            &credentials.api_key,
-            RECV_WINDOW,
-            &payload,
-            &credentials.api_secret,
-        );
+        let signature = credentials.sign_request(timestamp, self.recv_window, &payload)?;
 
         let mut headers = HeaderMap::new();
         headers.insert(
@@ -172,7 +751,7 @@ impl BybitClient {
         );
         headers.insert(
             "X-BAPI-RECV-WINDOW",
-            HeaderValue::try_from(RECV_WINDOW.to_string().as_str())
+            HeaderValue::try_from(self.recv_window.to_string().as_str())
                 .map_err(|e| BybitError::InvalidParameter(e.to_string()))?,
         );
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
@@ -181,6 +760,99 @@ impl BybitClient {
     }
 }
 
+/// Keeps the first four characters of a credential for log correlation
+#[cfg(feature = "tracing")]
+fn redact(value: &str) -> String {
+    match value.get(..4) {
+        Some(prefix) if value.len() > 8 => format!("{}****", prefix),
+        _ => "****".to_string(),
+    }
+}
+
+fn parse_recv_window(value: &str) -> Result<u64> {
+    value.trim().parse().map_err(|_| {
+        BybitError::InvalidParameter(format!(
+            "recv_window must be a number of ms, got '{}'",
+            value
+        ))
+    })
+}
+
+/// Checks that `url` is an absolute `https` URL and strips any trailing `/`
+fn validate_base_url(url: &str) -> Result<String> {
+    let parsed = reqwest::Url::parse(url)
+        .map_err(|e| BybitError::InvalidParameter(format!("invalid base URL '{}': {}", url, e)))?;
+    if parsed.scheme() != "https" || parsed.host_str().is_none() {
+        return Err(BybitError::InvalidParameter(format!(
+            "base URL '{}' must be an https URL",
+            url
+        )));
+    }
+    Ok(url.trim_end_matches('/').to_string())
+}
+
+fn parse_rate_limit_headers(headers: &HeaderMap) -> Option<RateLimitStatus> {
+    Some(RateLimitStatus {
+        limit: parse_header(headers, "X-Bapi-Limit")?,
+        remaining: parse_header(headers, "X-Bapi-Limit-Status")?,
+        reset_timestamp_ms: parse_header(headers, "X-Bapi-Limit-Reset-Timestamp")?,
+    })
+}
+
+fn parse_header<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Proxy used by the inner HTTP client
+#[derive(Debug, Clone)]
+enum ProxySetting {
+    /// reqwest's default: honour the `HTTP_PROXY`/`HTTPS_PROXY` environment
+    System,
+    Custom(Box<reqwest::Proxy>),
+    Disabled,
+}
+
+/// Everything the inner HTTP client is built from, kept so that changing one
+/// setting rebuilds the client without dropping the others
+#[derive(Debug, Clone)]
+struct HttpSettings {
+    timeout: Duration,
+    proxy: ProxySetting,
+    /// `None` keeps reqwest's default
+    pool_max_idle_per_host: Option<usize>,
+    /// `None` keeps reqwest's default; `Some(None)` disables the timeout
+    pool_idle_timeout: Option<Option<Duration>>,
+}
+
+impl Default for HttpSettings {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_TIMEOUT,
+            proxy: ProxySetting::System,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+        }
+    }
+}
+
+impl HttpSettings {
+    fn build(&self) -> reqwest::Client {
+        let mut builder = reqwest::Client::builder().timeout(self.timeout);
+        builder = match &self.proxy {
+            ProxySetting::System => builder,
+            ProxySetting::Custom(proxy) => builder.proxy(proxy.as_ref().clone()),
+            ProxySetting::Disabled => builder.no_proxy(),
+        };
+        if let Some(max_idle) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(idle_timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(idle_timeout);
+        }
+        builder.build().expect("Failed to create HTTP client")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,4 +872,689 @@ mod tests {
             .with_credentials("test_key".to_string(), "test_secret".to_string());
         assert!(client.credentials.is_some());
     }
+
+    #[test]
+    fn test_demo_client() {
+        assert_eq!(BybitClient::demo().base_url, "https://api-demo.bybit.com");
+    }
+
+    #[test]
+    fn test_with_base_url() {
+        let client = BybitClient::mainnet()
+            .with_base_url("https://api.bybit.nl/")
+            .unwrap();
+        assert_eq!(client.base_url, "https://api.bybit.nl");
+
+        let result = BybitClient::mainnet().with_base_url("http://api.bybit.kz");
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_validate_base_url() {
+        assert_eq!(
+            validate_base_url("https://api.bybit.com").unwrap(),
+            "https://api.bybit.com"
+        );
+        assert_eq!(
+            validate_base_url("https://api-testnet.bybit.com/").unwrap(),
+            "https://api-testnet.bybit.com"
+        );
+    }
+
+    #[test]
+    fn test_validate_base_url_rejects_invalid() {
+        for url in [
+            "api.bybit.com",
+            "http://api.bybit.com",
+            "https://",
+            "htps://api.bybit.com",
+        ] {
+            assert!(
+                matches!(validate_base_url(url), Err(BybitError::InvalidParameter(_))),
+                "{} should be rejected",
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn test_client_default_recv_window() {
+        let client = BybitClient::testnet();
+        assert_eq!(client.recv_window, 5000);
+    }
+
+    #[test]
+    fn test_client_with_recv_window() {
+        let client = BybitClient::testnet().with_recv_window(20000).unwrap();
+        assert_eq!(client.recv_window, 20000);
+    }
+
+    #[test]
+    fn test_client_with_recv_window_out_of_range() {
+        let result = BybitClient::testnet().with_recv_window(60001);
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+
+        let result = BybitClient::testnet().with_recv_window(0);
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_auth_headers_use_recv_window() {
+        let client = BybitClient::testnet().with_recv_window(15000).unwrap();
+        let credentials = Credentials::new("key".to_string(), "secret".to_string());
+        let headers = client
+            .build_auth_headers(
+                &reqwest::Method::GET,
+                "/v5/market/time",
+                None,
+                None,
+                &credentials,
+            )
+            .unwrap();
+        assert_eq!(headers["X-BAPI-RECV-WINDOW"], "15000");
+
+        let timestamp: i64 = headers["X-BAPI-TIMESTAMP"]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            headers["X-BAPI-SIGN"],
+            crate::auth::generate_signature(timestamp, "key", 15000, "", "secret").as_str()
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_redact() {
+        assert_eq!(redact("XXXXXXXXXXYYYY"), "XXXX****");
+        assert_eq!(redact("short"), "****");
+        assert_eq!(redact(""), "****");
+    }
+
+    #[test]
+    fn test_parse_recv_window() {
+        assert_eq!(parse_recv_window("20000").unwrap(), 20000);
+        assert!(matches!(
+            parse_recv_window("5s"),
+            Err(BybitError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_auth_headers_apply_time_offset() {
+        let client = BybitClient::testnet();
+        client.time_offset_ms.store(-3_600_000, Ordering::Relaxed);
+        let credentials = Credentials::new("key".to_string(), "secret".to_string());
+        let headers = client
+            .build_auth_headers(&reqwest::Method::GET, "/", None, None, &credentials)
+            .unwrap();
+
+        let timestamp: i64 = headers["X-BAPI-TIMESTAMP"]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        let expected = get_current_timestamp_ms() - 3_600_000;
+        assert!((expected - timestamp).abs() < 1000);
+    }
+
+    fn server_time_body(server_time_ms: i64) -> String {
+        format!(
+            r#"{{"retCode":0,"retMsg":"OK","result":{{"timeSecond":"{}","timeNano":"{}"}},"retExtInfo":{{}},"time":{}}}"#,
+            server_time_ms / 1000,
+            server_time_ms * 1_000_000,
+            server_time_ms
+        )
+    }
+
+    #[tokio::test]
+    async fn test_sync_time_measures_offset() {
+        let mut server = mockito::Server::new_async().await;
+        let server_time_ms = get_current_timestamp_ms() + 120_000;
+        server
+            .mock("GET", "/v5/market/time")
+            .with_body(server_time_body(server_time_ms))
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        assert_eq!(client.time_offset_ms(), 0);
+
+        let offset = client.sync_time().await.unwrap();
+        assert!((offset - 120_000).abs() < 1000, "offset {}", offset);
+        assert_eq!(client.clone().time_offset_ms(), offset);
+    }
+
+    #[tokio::test]
+    async fn test_auto_time_sync_runs_once_before_signed_requests() {
+        let mut server = mockito::Server::new_async().await;
+        let time_mock = server
+            .mock("GET", "/v5/market/time")
+            .with_body(server_time_body(get_current_timestamp_ms() - 60_000))
+            .expect(1)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/v5/account/info")
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"unifiedMarginStatus":4,"marginMode":"REGULAR_MARGIN","isMasterTrader":false,"dcpStatus":"OFF","updatedTime":"1697078946000"},"retExtInfo":{},"time":1}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url())
+            .with_credentials("key".into(), "secret".into())
+            .with_auto_time_sync();
+        client.get_account_info().await.unwrap();
+        client.get_account_info().await.unwrap();
+
+        time_mock.assert_async().await;
+        assert!((client.time_offset_ms() + 60_000).abs() < 1000);
+    }
+
+    #[tokio::test]
+    async fn test_requests_go_through_proxy() {
+        let mut proxy = mockito::Server::new_async().await;
+        let mock = proxy
+            .mock("GET", mockito::Matcher::Any)
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1","timeNano":"1"},"retExtInfo":{},"time":1}"#)
+            .create_async()
+            .await;
+
+        // The proxy must survive the client being rebuilt for a new timeout
+        let client = BybitClient::new("http://api.bybit.invalid".to_string())
+            .with_proxy_url(&proxy.url())
+            .unwrap()
+            .with_timeout(Duration::from_secs(5));
+        client.get_server_time().await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_with_proxy_url_rejects_invalid_url() {
+        let result = BybitClient::testnet().with_proxy_url("not a url");
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_client_default_timeout() {
+        let client = BybitClient::testnet();
+        assert_eq!(client.http.timeout, Duration::from_secs(10));
+
+        let client = BybitClient::testnet().with_timeout(Duration::from_secs(5));
+        assert_eq!(client.http.timeout, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_pool_settings_survive_later_builders() {
+        let client = BybitClient::testnet();
+        assert_eq!(client.http.pool_max_idle_per_host, None);
+        assert_eq!(client.http.pool_idle_timeout, None);
+
+        let client = BybitClient::testnet()
+            .with_pool_max_idle_per_host(4)
+            .with_pool_idle_timeout(Some(Duration::from_secs(300)))
+            .with_timeout(Duration::from_secs(5))
+            .with_no_proxy();
+        assert_eq!(client.http.pool_max_idle_per_host, Some(4));
+        assert_eq!(
+            client.http.pool_idle_timeout,
+            Some(Some(Duration::from_secs(300)))
+        );
+        assert_eq!(client.http.timeout, Duration::from_secs(5));
+        assert!(matches!(client.http.proxy, ProxySetting::Disabled));
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_maps_to_timeout_error() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+
+        let client =
+            BybitClient::new(format!("http://{}", addr)).with_timeout(Duration::from_millis(50));
+        let result = client.get_server_time().await;
+        assert!(matches!(result, Err(BybitError::Timeout(_))));
+
+        server.abort();
+    }
+
+    #[test]
+    fn test_retry_delay_is_capped_and_jittered() {
+        let retry = RetryConfig {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+        };
+
+        let first = retry.delay(1);
+        assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+
+        let third = retry.delay(3);
+        assert!(third >= Duration::from_millis(200) && third <= Duration::from_millis(400));
+
+        let capped = retry.delay(20);
+        assert!(capped >= Duration::from_millis(500) && capped <= Duration::from_millis(1000));
+    }
+
+    fn fast_retry() -> RetryConfig {
+        RetryConfig {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_rate_limit_until_attempts_exhausted() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/time")
+            .with_body(r#"{"retCode":10006,"retMsg":"Too many visits!","result":{},"retExtInfo":{},"time":1}"#)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_retry(fast_retry());
+        let result = client.get_server_time().await;
+
+        assert!(matches!(
+            result,
+            Err(BybitError::ApiError {
+                ret_code: 10006,
+                ..
+            })
+        ));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_retry_succeeds_after_two_failures() {
+        let mut server = mockito::Server::new_async().await;
+        let failing = server
+            .mock("GET", "/v5/market/time")
+            .with_body(r#"{"retCode":10006,"retMsg":"Too many visits!","result":{},"retExtInfo":{},"time":1}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let succeeding = server
+            .mock("GET", "/v5/market/time")
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1688639403","timeNano":"1688639403423213947"},"retExtInfo":{},"time":1688639403423}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let retry = RetryConfig {
+            base_delay: Duration::from_millis(1),
+            ..RetryConfig::with_max_retries(2)
+        };
+        assert_eq!(retry.max_attempts, 3);
+        let client = BybitClient::new(server.url()).with_retry(retry);
+        let time = client.get_server_time().await.unwrap();
+
+        assert_eq!(time.time_second, "1688639403");
+        failing.assert_async().await;
+        succeeding.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_does_not_retry_invalid_parameter() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/time")
+            .with_body(
+                r#"{"retCode":10001,"retMsg":"params error","result":{},"retExtInfo":{},"time":1}"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_retry(fast_retry());
+        let result = client.get_server_time().await;
+
+        assert!(matches!(
+            result,
+            Err(BybitError::ApiError {
+                ret_code: 10001,
+                ..
+            })
+        ));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_non_success_status_maps_to_http_status_error() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v5/market/time")
+            .with_status(503)
+            .with_body("<html><body>Service Unavailable</body></html>")
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        match client.get_server_time().await {
+            Err(BybitError::HttpStatus { status, body }) => {
+                assert_eq!(status, 503);
+                assert!(body.contains("Service Unavailable"));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_without_config() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/time")
+            .with_body(r#"{"retCode":10006,"retMsg":"Too many visits!","result":{},"retExtInfo":{},"time":1}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        assert!(client.get_server_time().await.is_err());
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_parse_rate_limit_headers() {
+        let mut headers = HeaderMap::new();
+        assert!(parse_rate_limit_headers(&headers).is_none());
+
+        headers.insert("X-Bapi-Limit", HeaderValue::from_static("10"));
+        headers.insert("X-Bapi-Limit-Status", HeaderValue::from_static("9"));
+        headers.insert(
+            "X-Bapi-Limit-Reset-Timestamp",
+            HeaderValue::from_static("1672738134824"),
+        );
+
+        let status = parse_rate_limit_headers(&headers).unwrap();
+        assert_eq!(status.limit, 10);
+        assert_eq!(status.remaining, 9);
+        assert_eq!(status.reset_timestamp_ms, 1672738134824);
+    }
+
+    #[tokio::test]
+    async fn test_last_rate_limit_is_recorded() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v5/market/time")
+            .with_header("X-Bapi-Limit", "50")
+            .with_header("X-Bapi-Limit-Status", "49")
+            .with_header("X-Bapi-Limit-Reset-Timestamp", "1672738134824")
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1","timeNano":"1"},"retExtInfo":{},"time":1}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        assert!(client.last_rate_limit().is_none());
+
+        client.get_server_time().await.unwrap();
+        let status = client.clone().last_rate_limit().unwrap();
+        assert_eq!(status.limit, 50);
+        assert_eq!(status.remaining, 49);
+        assert_eq!(status.reset_timestamp_ms, 1672738134824);
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_rate_limit_reports_reset() {
+        let reset = get_current_timestamp_ms() + 60_000;
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v5/market/time")
+            .with_header("X-Bapi-Limit", "50")
+            .with_header("X-Bapi-Limit-Status", "0")
+            .with_header("X-Bapi-Limit-Reset-Timestamp", &reset.to_string())
+            .with_body(r#"{"retCode":10006,"retMsg":"Too many visits!","result":{},"retExtInfo":{},"time":1}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        let result = client.get_server_time().await;
+
+        match result {
+            Err(BybitError::RateLimitExceeded {
+                limit_type,
+                limit_reset_ms: Some(reset_ms),
+            }) => {
+                assert_eq!(limit_type, "/v5/market/time");
+                assert!(reset_ms > 55_000 && reset_ms <= 60_000);
+            }
+            other => panic!("expected RateLimitExceeded, got {:?}", other),
+        }
+        assert_eq!(client.last_rate_limit().unwrap().remaining, 0);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_delays_requests_over_budget() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v5/market/time")
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1","timeNano":"1"},"retExtInfo":{},"time":1}"#)
+            .expect(6)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_rate_limit(5);
+        assert_eq!(client.available_permits(), Some(5));
+
+        // The sixth request needs a token that only refills 200ms after the start
+        let start = std::time::Instant::now();
+        for _ in 0..6 {
+            client.get_server_time().await.unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(190));
+    }
+
+    /// Mocks `GET /v5/account/info`, recording how old each request's
+    /// `X-BAPI-TIMESTAMP` is on arrival
+    async fn record_timestamp_age(
+        server: &mut mockito::ServerGuard,
+        expected_hits: usize,
+    ) -> Arc<Mutex<Vec<i64>>> {
+        let ages = Arc::new(Mutex::new(Vec::new()));
+        let recorded = ages.clone();
+        server
+            .mock("GET", "/v5/account/info")
+            .with_body_from_request(move |request| {
+                let timestamp: i64 = request.header("X-BAPI-TIMESTAMP")[0]
+                    .to_str()
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push(get_current_timestamp_ms() - timestamp);
+                br#"{"retCode":0,"retMsg":"OK","result":{"unifiedMarginStatus":4,"marginMode":"REGULAR_MARGIN","updatedTime":"1697078946000"},"retExtInfo":{},"time":1}"#.to_vec()
+            })
+            .expect(expected_hits)
+            .create_async()
+            .await;
+        ages
+    }
+
+    #[tokio::test]
+    async fn test_throttled_request_is_signed_after_waiting() {
+        let mut server = mockito::Server::new_async().await;
+        let ages = record_timestamp_age(&mut server, 2).await;
+
+        let client = BybitClient::new(server.url())
+            .with_credentials("key".into(), "secret".into())
+            .with_rate_limit(1);
+
+        // The second request waits about a second for a token
+        let start = std::time::Instant::now();
+        client.get_account_info().await.unwrap();
+        client.get_account_info().await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(900));
+
+        let ages = ages.lock().unwrap();
+        assert!(ages[1] < 500, "timestamp was {}ms old", ages[1]);
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_throttled_request_is_signed_after_waiting() {
+        let mut server = mockito::Server::new_async().await;
+        let ages = record_timestamp_age(&mut server, 2).await;
+
+        let client = BybitClient::new(server.url())
+            .with_credentials("key".into(), "secret".into())
+            .with_endpoint_rate_limit("/v5/account", 1);
+
+        let start = std::time::Instant::now();
+        client.get_account_info().await.unwrap();
+        client.get_account_info().await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(900));
+
+        let ages = ages.lock().unwrap();
+        assert!(ages[1] < 500, "timestamp was {}ms old", ages[1]);
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_rate_limits_are_separate() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v5/market/time")
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1","timeNano":"1"},"retExtInfo":{},"time":1}"#)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url())
+            .with_endpoint_rate_limit("/v5/order", 1)
+            .with_endpoint_rate_limit("/v5/market", 2)
+            .with_endpoint_rate_limit("/v5/market/time", 3);
+        assert_eq!(
+            client
+                .endpoint_rate_limiter("/v5/market/tickers")
+                .unwrap()
+                .available_permits(),
+            2
+        );
+        assert!(client.endpoint_rate_limiter("/v5/position/list").is_none());
+
+        // Only the `/v5/market/time` bucket is drawn from, and it holds three
+        let start = std::time::Instant::now();
+        for _ in 0..3 {
+            client.get_server_time().await.unwrap();
+        }
+        assert!(start.elapsed() < Duration::from_millis(100));
+        let time_bucket = client.endpoint_rate_limiter("/v5/market/time").unwrap();
+        assert_eq!(time_bucket.available_permits(), 0);
+        let order_bucket = client.endpoint_rate_limiter("/v5/order/create").unwrap();
+        assert_eq!(order_bucket.available_permits(), 1);
+    }
+
+    #[derive(Debug, Default)]
+    struct CapturingLogger {
+        lines: Mutex<Vec<String>>,
+    }
+
+    impl RequestLogger for CapturingLogger {
+        fn on_request(&self, method: &str, url: &str, body: Option<&str>) {
+            self.lines
+                .lock()
+                .unwrap()
+                .push(format!("{} {} {}", method, url, body.unwrap_or("")));
+        }
+
+        fn on_response(&self, status: u16, body: &str) {
+            self.lines
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", status, body));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_logger_records_request_and_response() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/v5/order/cancel")
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"orderId":"1","orderLinkId":""},"retExtInfo":{},"time":1}"#)
+            .create_async()
+            .await;
+
+        let logger = Arc::new(CapturingLogger::default());
+        let client = BybitClient::new(server.url())
+            .with_credentials("test_key".into(), "test_secret".into())
+            .with_logger(logger.clone());
+        client.cancel_order("linear", "1", "BTCUSDT").await.unwrap();
+
+        let lines = logger.lines.lock().unwrap();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with(&format!("POST {}/v5/order/cancel {{", server.url())));
+        assert!(lines[0].contains(r#""orderId":"1""#));
+        assert!(lines[1].starts_with(r#"200 {"retCode":0"#));
+        assert!(lines.iter().all(|line| !line.contains("test_secret")));
+    }
+
+    #[tokio::test]
+    async fn test_logger_sees_query_string() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/v5/market/tickers")
+            .match_query(mockito::Matcher::Any)
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"list":[],"nextPageCursor":null},"retExtInfo":{},"time":1}"#)
+            .create_async()
+            .await;
+
+        let logger = Arc::new(CapturingLogger::default());
+        let client = BybitClient::new(server.url()).with_logger(logger.clone());
+        client.get_tickers("linear").await.unwrap();
+
+        let lines = logger.lines.lock().unwrap();
+        assert_eq!(
+            lines[0],
+            format!("GET {}/v5/market/tickers?category=linear ", server.url())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_default_headers_are_sent_with_auth_headers() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/time")
+            .match_header("user-agent", "my-bot/1.0")
+            .match_header("x-gateway-token", "abc")
+            .match_header("x-bapi-api-key", "test_key")
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1","timeNano":"1"},"retExtInfo":{},"time":1}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url())
+            .with_credentials("test_key".into(), "test_secret".into())
+            .with_user_agent("my-bot/1.0")
+            .unwrap()
+            .with_default_header("X-Gateway-Token", "old")
+            .unwrap()
+            .with_default_header("X-Gateway-Token", "abc")
+            .unwrap();
+        client.get_server_time().await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_with_default_header_rejects_reserved_and_invalid() {
+        for (name, value) in [
+            ("X-BAPI-API-KEY", "other"),
+            ("bad header", "value"),
+            ("X-Custom", "line\nbreak"),
+        ] {
+            let result = BybitClient::testnet().with_default_header(name, value);
+            assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+        }
+    }
+
+    #[test]
+    fn test_available_permits_without_rate_limit() {
+        assert_eq!(BybitClient::testnet().available_permits(), None);
+    }
 }
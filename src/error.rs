@@ -5,6 +5,7 @@
 //! # Example
 //!
 //! ```rust,no_run
+//! use rusty_bybit::error::ApiErrorKind;
 //! use rusty_bybit::{BybitError, BybitClient};
 //!
 //! #[tokio::main]
@@ -13,23 +14,21 @@
 //!
 //!     match client.get_server_time().await {
 //!         Ok(time) => println!("Server time: {}", time.time_second),
-//!         Err(BybitError::ApiError { ret_code, ret_msg }) => {
-//!             if ret_code == 10006 {
-//!                 eprintln!("Rate limit exceeded: {}", ret_msg);
-//!             } else if ret_code == 110004 {
-//!                 eprintln!("Insufficient balance: {}", ret_msg);
-//!             } else {
-//!                 eprintln!("API error {}: {}", ret_code, ret_msg);
-//!             }
-//!         }
-//!         Err(e) => eprintln!("Error: {}", e),
+//!         Err(e) => match e.api_error_kind() {
+//!             Some(ApiErrorKind::RateLimit) => eprintln!("Rate limit exceeded: {}", e),
+//!             Some(ApiErrorKind::InsufficientBalance) => eprintln!("Insufficient balance: {}", e),
+//!             Some(_) => eprintln!("API error {:?}: {}", e.ret_code(), e),
+//!             None => eprintln!("Error: {}", e),
+//!         },
 //!     }
 //! }
 //! ```
 
 #[derive(Debug, thiserror::Error)]
 pub enum BybitError {
-    RequestError(#[from] reqwest::Error),
+    RequestError(#[source] reqwest::Error),
+
+    Timeout(#[source] reqwest::Error),
 
     ApiError {
         ret_code: i32,
@@ -44,6 +43,9 @@ pub enum BybitError {
 
     AuthenticationError(String),
 
+    /// A rate-limit `retCode` while the `X-Bapi-Limit-Status` header showed
+    /// no requests left; `limit_type` is the request path and `limit_reset_ms`
+    /// the time until the window resets
     RateLimitExceeded {
         limit_type: String,
         limit_reset_ms: Option<u64>,
@@ -57,12 +59,25 @@ pub enum BybitError {
     MissingRequiredField {
         field_name: String,
     },
+
+    WebSocketError(String),
+
+    ParseError {
+        field: String,
+        value: String,
+    },
+
+    HttpStatus {
+        status: u16,
+        body: String,
+    },
 }
 
 impl std::fmt::Display for BybitError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             BybitError::RequestError(e) => write!(f, "HTTP request failed: {}", e),
+            BybitError::Timeout(e) => write!(f, "Request timed out: {}", e),
             BybitError::ApiError { ret_code, ret_msg } => {
                 write!(f, "API error (code {}): {}", ret_code, ret_msg)
             }
@@ -87,8 +102,105 @@ impl std::fmt::Display for BybitError {
             BybitError::MissingRequiredField { field_name } => {
                 write!(f, "Missing required field: {}", field_name)
             }
+            BybitError::WebSocketError(msg) => write!(f, "WebSocket error: {}", msg),
+            BybitError::ParseError { field, value } => {
+                write!(f, "Failed to parse {}: '{}'", field, value)
+            }
+            BybitError::HttpStatus { status, body } => {
+                write!(f, "HTTP status {}: {}", status, body)
+            }
+        }
+    }
+}
+
+impl From<reqwest::Error> for BybitError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            BybitError::Timeout(e)
+        } else {
+            BybitError::RequestError(e)
+        }
+    }
+}
+
+/// Category of a Bybit `retCode`, following Bybit's V5 error code table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiErrorKind {
+    /// `10006` too many visits, `10018` IP rate limit exceeded
+    RateLimit,
+    /// `10000` server timeout, `10016` server error or busy
+    Server,
+    /// `10002` request timestamp outside `recv_window`; sync the clock
+    Timestamp,
+    /// `10003` invalid API key, `10004` signature error, `10005` permission
+    /// denied, `10007` authentication failed, `10009` IP banned, `10010`
+    /// unmatched IP
+    Auth,
+    /// `10001` parameter error, `10017` route not found
+    InvalidRequest,
+    /// `110004` wallet balance, `110006`/`110007`/`110012` available balance,
+    /// `170131` spot balance
+    InsufficientBalance,
+    /// `110001` order does not exist, `110003` price out of range, `110008`
+    /// order finished, `110009` too many stop orders, `110010` order already
+    /// cancelled, `110017` reduce-only violated, `110020` too many active orders
+    InvalidOrder,
+    /// Any code not listed above
+    Other,
+}
+
+impl ApiErrorKind {
+    pub fn from_code(ret_code: i32) -> Self {
+        match ret_code {
+            10006 | 10018 => ApiErrorKind::RateLimit,
+            10000 | 10016 => ApiErrorKind::Server,
+            10002 => ApiErrorKind::Timestamp,
+            10003 | 10004 | 10005 | 10007 | 10009 | 10010 => ApiErrorKind::Auth,
+            10001 | 10017 => ApiErrorKind::InvalidRequest,
+            110004 | 110006 | 110007 | 110012 | 170131 => ApiErrorKind::InsufficientBalance,
+            110001 | 110003 | 110008 | 110009 | 110010 | 110017 | 110020 => {
+                ApiErrorKind::InvalidOrder
+            }
+            _ => ApiErrorKind::Other,
         }
     }
+
+    /// Rate limits and server-side failures; everything else fails the same
+    /// way when repeated
+    pub fn is_retryable(self) -> bool {
+        matches!(self, ApiErrorKind::RateLimit | ApiErrorKind::Server)
+    }
+}
+
+impl BybitError {
+    /// Whether the failure is transient and the request may succeed if repeated:
+    /// connection failures, timeouts, HTTP 429 and 5xx responses, and
+    /// rate-limit or server-side API errors.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            BybitError::RequestError(e) => e.is_connect(),
+            BybitError::Timeout(_) => true,
+            BybitError::HttpStatus { status, .. } => *status == 429 || *status >= 500,
+            BybitError::ApiError { ret_code, .. } => {
+                ApiErrorKind::from_code(*ret_code).is_retryable()
+            }
+            BybitError::RateLimitExceeded { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Bybit's `retCode`, for errors returned by the API
+    pub fn ret_code(&self) -> Option<i32> {
+        match self {
+            BybitError::ApiError { ret_code, .. } => Some(*ret_code),
+            _ => None,
+        }
+    }
+
+    /// Category of the `retCode`, for errors returned by the API
+    pub fn api_error_kind(&self) -> Option<ApiErrorKind> {
+        self.ret_code().map(ApiErrorKind::from_code)
+    }
 }
 
 pub type Result<T> = std::result::Result<T, BybitError>;
@@ -182,6 +294,93 @@ mod tests {
         assert!(display.contains("symbol"));
     }
 
+    #[test]
+    fn test_bybit_error_display_websocket_error() {
+        let error = BybitError::WebSocketError("connection closed".to_string());
+
+        let display = format!("{}", error);
+        assert!(display.contains("WebSocket error"));
+        assert!(display.contains("connection closed"));
+    }
+
+    #[test]
+    fn test_bybit_error_display_http_status() {
+        let error = BybitError::HttpStatus {
+            status: 503,
+            body: "<html>Service Unavailable</html>".to_string(),
+        };
+
+        let display = format!("{}", error);
+        assert!(display.contains("503"));
+        assert!(display.contains("Service Unavailable"));
+    }
+
+    #[test]
+    fn test_bybit_error_is_retryable() {
+        let rate_limited = BybitError::ApiError {
+            ret_code: 10006,
+            ret_msg: "Too many visits!".to_string(),
+        };
+        assert!(rate_limited.is_retryable());
+
+        let server_error = BybitError::ApiError {
+            ret_code: 10016,
+            ret_msg: "Server error".to_string(),
+        };
+        assert!(server_error.is_retryable());
+
+        let invalid = BybitError::ApiError {
+            ret_code: 10001,
+            ret_msg: "params error".to_string(),
+        };
+        assert!(!invalid.is_retryable());
+        assert!(!BybitError::InvalidParameter("qty".to_string()).is_retryable());
+
+        let http_status = |status| BybitError::HttpStatus {
+            status,
+            body: String::new(),
+        };
+        assert!(http_status(502).is_retryable());
+        assert!(http_status(429).is_retryable());
+        assert!(!http_status(404).is_retryable());
+    }
+
+    #[test]
+    fn test_api_error_kind_from_code() {
+        assert_eq!(ApiErrorKind::from_code(10006), ApiErrorKind::RateLimit);
+        assert_eq!(ApiErrorKind::from_code(10018), ApiErrorKind::RateLimit);
+        assert_eq!(ApiErrorKind::from_code(10000), ApiErrorKind::Server);
+        assert_eq!(ApiErrorKind::from_code(10002), ApiErrorKind::Timestamp);
+        assert_eq!(ApiErrorKind::from_code(10004), ApiErrorKind::Auth);
+        assert_eq!(ApiErrorKind::from_code(10001), ApiErrorKind::InvalidRequest);
+        assert_eq!(
+            ApiErrorKind::from_code(110004),
+            ApiErrorKind::InsufficientBalance
+        );
+        assert_eq!(ApiErrorKind::from_code(110017), ApiErrorKind::InvalidOrder);
+        assert_eq!(ApiErrorKind::from_code(99999), ApiErrorKind::Other);
+
+        assert!(ApiErrorKind::Server.is_retryable());
+        assert!(!ApiErrorKind::InsufficientBalance.is_retryable());
+    }
+
+    #[test]
+    fn test_bybit_error_ret_code() {
+        let error = BybitError::ApiError {
+            ret_code: 110004,
+            ret_msg: "Insufficient wallet balance".to_string(),
+        };
+        assert_eq!(error.ret_code(), Some(110004));
+        assert_eq!(
+            error.api_error_kind(),
+            Some(ApiErrorKind::InsufficientBalance)
+        );
+
+        let error = BybitError::InvalidParameter("qty".to_string());
+        assert_eq!(error.ret_code(), None);
+        assert_eq!(error.api_error_kind(), None);
+    }
+
     #[test]
     fn test_bybit_error_debug() {
         let error = BybitError::ApiError {
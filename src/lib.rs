@@ -1,12 +1,21 @@
 pub mod auth;
 pub mod client;
 pub mod error;
+pub mod logging;
 pub mod types;
 
 pub mod account;
+pub mod asset;
 pub mod market;
+pub mod pagination;
 pub mod trade;
+pub mod validation;
+pub mod ws;
 
-pub use client::BybitClient;
-pub use error::BybitError;
-pub use types::{CreateOrderRequest, CreateOrderResponse};
+mod rate_limit;
+#[cfg(test)]
+mod test_support;
+
+pub use client::{BybitClient, RetryConfig};
+pub use error::{ApiErrorKind, BybitError};
+pub use types::{AmendOrderRequest, CreateOrderRequest, CreateOrderResponse, TradingStopRequest};
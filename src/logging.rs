@@ -0,0 +1,63 @@
+//! Request and response logging hook
+//!
+//! Install a [`RequestLogger`] with
+//! [`BybitClient::with_logger`](crate::BybitClient::with_logger) to observe every
+//! REST call, e.g. when debugging signatures. Loggers only see the method, the
+//! URL including its query string, and the bodies: the authentication headers
+//! carrying the API key and signature are never passed on, and the API secret is
+//! never part of a request.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::sync::Arc;
+//! use rusty_bybit::BybitClient;
+//! use rusty_bybit::logging::RequestLogger;
+//!
+//! #[derive(Debug)]
+//! struct StderrLogger;
+//!
+//! impl RequestLogger for StderrLogger {
+//!     fn on_request(&self, method: &str, url: &str, body: Option<&str>) {
+//!         eprintln!("> {} {} {}", method, url, body.unwrap_or(""));
+//!     }
+//!
+//!     fn on_response(&self, status: u16, body: &str) {
+//!         eprintln!("< {} {}", status, body);
+//!     }
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = BybitClient::testnet().with_logger(Arc::new(StderrLogger));
+//!     client.get_server_time().await.unwrap();
+//! }
+//! ```
+
+/// Receives every REST request before it is sent and its response once read
+///
+/// Called once per attempt, so retried requests are logged each time.
+pub trait RequestLogger: Send + Sync + std::fmt::Debug {
+    /// `url` includes the query string; `body` is the JSON body of POST requests
+    fn on_request(&self, method: &str, url: &str, body: Option<&str>);
+
+    /// HTTP status and raw body, before the `retCode` is checked
+    fn on_response(&self, status: u16, body: &str);
+}
+
+/// Logs requests and responses as `tracing` debug events with the
+/// `rusty_bybit::http` target; requires the `tracing` feature
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingLogger;
+
+#[cfg(feature = "tracing")]
+impl RequestLogger for TracingLogger {
+    fn on_request(&self, method: &str, url: &str, body: Option<&str>) {
+        tracing::debug!(target: "rusty_bybit::http", method, url, body, "request");
+    }
+
+    fn on_response(&self, status: u16, body: &str) {
+        tracing::debug!(target: "rusty_bybit::http", status, body, "response");
+    }
+}
@@ -4,34 +4,139 @@
 //!
 //! # Example
 //!
-//! ````rust,no_run
+//! ```rust,no_run
 //! use rusty_bybit::BybitClient;
+//! use rusty_bybit::types::Category;
 //!
 //! #[tokio::main]
 //! async fn main() {
 //!     let client = BybitClient::testnet();
-//!     let tickers = client.get_tickers("linear").await.unwrap();
+//!     let tickers = client.get_tickers(Category::Linear).await.unwrap();
 //!     println!("First ticker: {}", tickers.list[0].symbol);
 //! }
 //! ```
 
 use crate::client::BybitClient;
-use crate::error::Result;
-use crate::types::{InstrumentList, OrderBook, ServerTime, TickerList};
+use crate::error::{BybitError, Result};
+use crate::types::{
+    Category, DeliveryPriceList, InstrumentList, InsuranceList, Interval, KlineList, Meta,
+    OrderBook, RecentTradeList, RiskLimitList, ServerTime, TickerList,
+};
 
 impl BybitClient {
     pub async fn get_server_time(&self) -> Result<ServerTime> {
         self.get("/v5/market/time", None).await
     }
 
+    /// Server time together with the round-trip latency and local clock skew
+    pub async fn get_server_time_with_meta(&self) -> Result<(ServerTime, Meta)> {
+        self.get_timed("/v5/market/time", None).await
+    }
+
     pub async fn get_kline(
         &self,
-        category: &str,
+        category: impl AsRef<str>,
+        symbol: &str,
+        interval: &str,
+        start: Option<i64>,
+        end: Option<i64>,
+    ) -> Result<KlineList> {
+        self.kline(
+            "/v5/market/kline",
+            category,
+            symbol,
+            interval,
+            start,
+            end,
+            None,
+        )
+        .await
+    }
+
+    /// Mark price candles; `volume` and `turnover` are left empty
+    pub async fn get_mark_price_kline(
+        &self,
+        category: impl AsRef<str>,
+        symbol: &str,
+        interval: &str,
+        start: Option<i64>,
+        end: Option<i64>,
+    ) -> Result<KlineList> {
+        self.kline(
+            "/v5/market/mark-price-kline",
+            category,
+            symbol,
+            interval,
+            start,
+            end,
+            None,
+        )
+        .await
+    }
+
+    /// Index price candles; `volume` and `turnover` are left empty
+    pub async fn get_index_price_kline(
+        &self,
+        category: impl AsRef<str>,
+        symbol: &str,
+        interval: &str,
+        start: Option<i64>,
+        end: Option<i64>,
+    ) -> Result<KlineList> {
+        self.kline(
+            "/v5/market/index-price-kline",
+            category,
+            symbol,
+            interval,
+            start,
+            end,
+            None,
+        )
+        .await
+    }
+
+    /// Premium index price candles; only `linear` and `inverse` have a
+    /// premium index. `volume` and `turnover` are left empty.
+    pub async fn get_premium_index_price_kline(
+        &self,
+        category: impl AsRef<str>,
+        symbol: &str,
+        interval: &str,
+        start: Option<i64>,
+        end: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<KlineList> {
+        let category = Category::try_from(category.as_ref())?;
+        if !matches!(category, Category::Linear | Category::Inverse) {
+            return Err(BybitError::InvalidParameter(format!(
+                "premium index klines are only available for linear and inverse, got {}",
+                category.as_str()
+            )));
+        }
+        self.kline(
+            "/v5/market/premium-index-price-kline",
+            category,
+            symbol,
+            interval,
+            start,
+            end,
+            limit,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn kline(
+        &self,
+        path: &str,
+        category: impl AsRef<str>,
         symbol: &str,
         interval: &str,
         start: Option<i64>,
         end: Option<i64>,
-    ) -> Result<serde_json::Value> {
+        limit: Option<u32>,
+    ) -> Result<KlineList> {
+        let category = Category::try_from(category.as_ref())?.as_str();
         let mut params: Vec<(String, String)> = vec![
             ("category".to_string(), category.to_string()),
             ("symbol".to_string(), symbol.to_string()),
@@ -46,25 +151,62 @@ impl BybitClient {
             params.push(("end".to_string(), e.to_string()));
         }
 
+        if let Some(l) = limit {
+            params.push(("limit".to_string(), l.to_string()));
+        }
+
         let query: Vec<(&str, &str)> = params
             .iter()
             .map(|(k, v)| (k.as_str(), v.as_str()))
             .collect();
 
-        self.get("/v5/market/kline", Some(query)).await
+        self.get(path, Some(query)).await
+    }
+
+    /// Like [`get_kline`](Self::get_kline), with the interval checked at compile time
+    pub async fn get_kline_typed(
+        &self,
+        category: impl AsRef<str>,
+        symbol: &str,
+        interval: Interval,
+        start: Option<i64>,
+        end: Option<i64>,
+    ) -> Result<KlineList> {
+        self.get_kline(category, symbol, interval.as_str(), start, end)
+            .await
     }
 
-    pub async fn get_tickers(&self, category: &str) -> Result<TickerList> {
+    pub async fn get_tickers(&self, category: impl AsRef<str>) -> Result<TickerList> {
+        let category = Category::try_from(category.as_ref())?.as_str();
         let query = vec![("category", category)];
         self.get("/v5/market/tickers", Some(query)).await
     }
 
+    /// Option tickers for `base_coin`, with [`greeks`](crate::types::Ticker::greeks)
+    /// set; Bybit requires a base coin for the option category. `exp_date`
+    /// narrows the result to one expiry, e.g. `25DEC22`.
+    pub async fn get_option_tickers(
+        &self,
+        base_coin: &str,
+        exp_date: Option<&str>,
+    ) -> Result<TickerList> {
+        let mut query = vec![
+            ("category", Category::Option.as_str()),
+            ("baseCoin", base_coin),
+        ];
+        if let Some(e) = exp_date {
+            query.push(("expDate", e));
+        }
+        self.get("/v5/market/tickers", Some(query)).await
+    }
+
     pub async fn get_orderbook(
         &self,
-        category: &str,
+        category: impl AsRef<str>,
         symbol: &str,
         limit: u32,
     ) -> Result<OrderBook> {
+        let category = Category::try_from(category.as_ref())?.as_str();
         let limit_str = limit.to_string();
         let query = vec![
             ("category", category),
@@ -74,10 +216,119 @@ impl BybitClient {
         self.get("/v5/market/orderbook", Some(query)).await
     }
 
-    pub async fn get_instruments(&self, category: &str) -> Result<InstrumentList> {
-        let query = vec![("category", category)];
+    /// Latest public trades for `symbol`
+    ///
+    /// `limit` defaults to Bybit's page size when unset and may be at most 60
+    /// for spot and 1000 for the other categories.
+    pub async fn get_recent_trades(
+        &self,
+        category: impl AsRef<str>,
+        symbol: &str,
+        limit: Option<u32>,
+    ) -> Result<RecentTradeList> {
+        let category = Category::try_from(category.as_ref())?;
+        let max = match category {
+            Category::Spot => 60,
+            _ => 1000,
+        };
+        let limit = match limit {
+            Some(l) if l == 0 || l > max => {
+                return Err(BybitError::InvalidParameter(format!(
+                    "recent trades limit for {} must be 1 to {}, got {}",
+                    category.as_str(),
+                    max,
+                    l
+                )));
+            }
+            l => l.map(|l| l.to_string()),
+        };
+
+        let mut query = vec![("category", category.as_str()), ("symbol", symbol)];
+        if let Some(l) = &limit {
+            query.push(("limit", l.as_str()));
+        }
+        self.get("/v5/market/recent-trade", Some(query)).await
+    }
+
+    /// Instrument specifications, including the lot size and price filters
+    /// needed to format valid orders. Pass `symbol` to fetch a single instrument.
+    pub async fn get_instruments(
+        &self,
+        category: impl AsRef<str>,
+        symbol: Option<&str>,
+    ) -> Result<InstrumentList> {
+        let category = Category::try_from(category.as_ref())?.as_str();
+        let mut query = vec![("category", category)];
+        if let Some(s) = symbol {
+            query.push(("symbol", s));
+        }
         self.get("/v5/market/instruments-info", Some(query)).await
     }
+
+    /// Tiered risk limits for `symbol`, or for every symbol in the category
+    /// when unset. Only `linear` and `inverse` have risk limits.
+    pub async fn get_risk_limit(
+        &self,
+        category: impl AsRef<str>,
+        symbol: Option<&str>,
+    ) -> Result<RiskLimitList> {
+        let category = Category::try_from(category.as_ref())?;
+        if !matches!(category, Category::Linear | Category::Inverse) {
+            return Err(BybitError::InvalidParameter(format!(
+                "risk limits are only available for linear and inverse, got {}",
+                category.as_str()
+            )));
+        }
+        let mut query = vec![("category", category.as_str())];
+        if let Some(s) = symbol {
+            query.push(("symbol", s));
+        }
+        self.get("/v5/market/risk-limit", Some(query)).await
+    }
+
+    /// Insurance fund balances, for `coin` only when set
+    pub async fn get_insurance(&self, coin: Option<&str>) -> Result<InsuranceList> {
+        let mut query = Vec::new();
+        if let Some(c) = coin {
+            query.push(("coin", c));
+        }
+        self.get("/v5/market/insurance", Some(query)).await
+    }
+
+    /// Settlement prices of expired contracts, newest first. Spot has no
+    /// delivery, so only `linear` (futures), `inverse` and `option` are accepted.
+    /// Pass the previous page's `next_page_cursor` as `cursor` for older prices.
+    pub async fn get_delivery_price(
+        &self,
+        category: impl AsRef<str>,
+        symbol: Option<&str>,
+        base_coin: Option<&str>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<DeliveryPriceList> {
+        let category = Category::try_from(category.as_ref())?;
+        if category == Category::Spot {
+            return Err(BybitError::InvalidParameter(
+                "delivery prices are not available for spot".to_string(),
+            ));
+        }
+        let limit = limit.map(|l| l.to_string());
+
+        let mut query = vec![("category", category.as_str())];
+        if let Some(s) = symbol {
+            query.push(("symbol", s));
+        }
+        if let Some(b) = base_coin {
+            query.push(("baseCoin", b));
+        }
+        if let Some(l) = &limit {
+            query.push(("limit", l.as_str()));
+        }
+        if let Some(c) = cursor {
+            query.push(("cursor", c));
+        }
+        self.get("/v5/market/delivery-price", Some(query)).await
+    }
 }
 
 #[cfg(test)]
@@ -173,4 +424,416 @@ mod tests {
         assert!(query.contains(&("start", "1670601600000")));
         assert!(query.contains(&("end", "1670608800000")));
     }
+
+    #[tokio::test]
+    async fn test_get_kline_typed_response() {
+        use crate::client::BybitClient;
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/kline")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("category".into(), "linear".into()),
+                Matcher::UrlEncoded("symbol".into(), "BTCUSDT".into()),
+                Matcher::UrlEncoded("interval".into(), "60".into()),
+                Matcher::UrlEncoded("start".into(), "1670601600000".into()),
+                Matcher::UrlEncoded("end".into(), "1670608800000".into()),
+            ]))
+            .with_body(
+                r#"{
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {
+                        "symbol": "BTCUSDT",
+                        "category": "linear",
+                        "list": [
+                            ["1670608800000", "17071", "17073", "17027", "17055.5", "268611", "15.74462667"],
+                            ["1670605200000", "17071.5", "17071.5", "17061", "17071", "4177", "0.24469757"]
+                        ]
+                    },
+                    "retExtInfo": {},
+                    "time": 1672025956592
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        let klines = client
+            .get_kline(
+                "linear",
+                "BTCUSDT",
+                "60",
+                Some(1670601600000),
+                Some(1670608800000),
+            )
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(klines.list.len(), 2);
+        assert_eq!(klines.list[1].start_time, 1670605200000);
+        assert_eq!(klines.list[1].open, "17071.5");
+        assert_eq!(klines.list[1].close, "17071");
+        assert_eq!(klines.list[1].turnover, "0.24469757");
+    }
+
+    #[tokio::test]
+    async fn test_get_mark_and_index_price_kline() {
+        use crate::client::BybitClient;
+        use mockito::Matcher;
+
+        let body = r#"{"retCode":0,"retMsg":"OK","result":{"symbol":"BTCUSDT","category":"linear","list":[["1670608800000","17164.16","17164.16","17121.5","17131.64"]]},"retExtInfo":{},"time":1672026361839}"#;
+        let query = || Matcher::Exact("category=linear&symbol=BTCUSDT&interval=15".into());
+
+        let mut server = mockito::Server::new_async().await;
+        let mark = server
+            .mock("GET", "/v5/market/mark-price-kline")
+            .match_query(query())
+            .with_body(body)
+            .create_async()
+            .await;
+        let index = server
+            .mock("GET", "/v5/market/index-price-kline")
+            .match_query(query())
+            .with_body(body)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        let mark_klines = client
+            .get_mark_price_kline("linear", "BTCUSDT", "15", None, None)
+            .await
+            .unwrap();
+        let index_klines = client
+            .get_index_price_kline("linear", "BTCUSDT", "15", None, None)
+            .await
+            .unwrap();
+
+        mark.assert_async().await;
+        index.assert_async().await;
+        for klines in [mark_klines, index_klines] {
+            assert_eq!(klines.list[0].close, "17131.64");
+            assert!(klines.list[0].volume.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_premium_index_price_kline() {
+        use crate::client::BybitClient;
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/premium-index-price-kline")
+            .match_query(Matcher::Exact(
+                "category=linear&symbol=BTCUSDT&interval=D&limit=1".into(),
+            ))
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"symbol":"BTCUSDT","category":"linear","list":[["1672012800000","-0.000183","0.000129","-0.000416","-0.000083"]]},"retExtInfo":{},"time":1672026679044}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        let klines = client
+            .get_premium_index_price_kline("linear", "BTCUSDT", "D", None, None, Some(1))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(klines.list[0].start_time, 1672012800000);
+        assert_eq!(klines.list[0].open, "-0.000183");
+        assert_eq!(klines.list[0].close, "-0.000083");
+    }
+
+    #[tokio::test]
+    async fn test_get_premium_index_price_kline_rejects_spot() {
+        use crate::client::BybitClient;
+        use crate::error::BybitError;
+
+        let client = BybitClient::new("http://127.0.0.1:9".to_string());
+        for category in ["spot", "option"] {
+            let result = client
+                .get_premium_index_price_kline(category, "BTCUSDT", "D", None, None, None)
+                .await;
+            assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_instruments_by_symbol() {
+        use crate::client::BybitClient;
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/instruments-info")
+            .match_query(Matcher::Exact("category=linear&symbol=BTCUSDT".into()))
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"category":"linear","list":[{"symbol":"BTCUSDT","contractType":"LinearPerpetual","status":"Trading","baseCoin":"BTC","quoteCoin":"USDT","settleCoin":"USDT","priceScale":"2","priceFilter":{"minPrice":"0.10","maxPrice":"1999999.80","tickSize":"0.10"},"lotSizeFilter":{"maxOrderQty":"1190.000","minOrderQty":"0.001","qtyStep":"0.001"}}],"nextPageCursor":""},"retExtInfo":{},"time":1672712495660}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        let instruments = client
+            .get_instruments("linear", Some("BTCUSDT"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        let instrument = &instruments.list[0];
+        assert_eq!(
+            instrument
+                .lot_size_filter
+                .as_ref()
+                .unwrap()
+                .qty_step
+                .as_deref(),
+            Some("0.001")
+        );
+        assert_eq!(instrument.price_filter.as_ref().unwrap().tick_size, "0.10");
+    }
+
+    #[tokio::test]
+    async fn test_get_risk_limit() {
+        use crate::client::BybitClient;
+        use crate::error::BybitError;
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/risk-limit")
+            .match_query(Matcher::Exact("category=linear&symbol=BTCUSDT".into()))
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"category":"linear","list":[{"id":1,"symbol":"BTCUSDT","riskLimitValue":"2000000","maintenanceMargin":"0.005","initialMargin":"0.01","isLowestRisk":1,"maxLeverage":"100.00","mmDeduction":""},{"id":2,"symbol":"BTCUSDT","riskLimitValue":"2600000","maintenanceMargin":"0.0055","initialMargin":"0.0111","isLowestRisk":0,"maxLeverage":"90.00","mmDeduction":"1000"}]},"retExtInfo":{},"time":1672054488010}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        let limits = client
+            .get_risk_limit("linear", Some("BTCUSDT"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(limits.list.len(), 2);
+        assert_eq!(limits.list[1].id, 2);
+        assert_eq!(limits.list[1].max_leverage, "90.00");
+
+        let result = client.get_risk_limit("spot", None).await;
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_delivery_price() {
+        use crate::client::BybitClient;
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/delivery-price")
+            .match_query(Matcher::Exact(
+                "category=option&baseCoin=ETH&limit=1&cursor=page-2".into(),
+            ))
+            .with_body(r#"{"retCode":0,"retMsg":"success","result":{"category":"option","nextPageCursor":"emptyCursor","list":[{"symbol":"ETH-26DEC22-1400-C","deliveryPrice":"1220.728594450","deliveryTime":"1672041600000"}]},"retExtInfo":{},"time":1672055336993}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        let prices = client
+            .get_delivery_price("option", None, Some("ETH"), Some(1), Some("page-2"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(prices.category, "option");
+        let price = &prices.list[0];
+        assert_eq!(price.symbol, "ETH-26DEC22-1400-C");
+        assert_eq!(price.delivery_time, "1672041600000");
+        assert_eq!(price.delivery_price_f64().unwrap(), 1220.72859445);
+    }
+
+    #[tokio::test]
+    async fn test_get_option_tickers() {
+        use crate::client::BybitClient;
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/tickers")
+            .match_query(Matcher::Exact(
+                "category=option&baseCoin=BTC&expDate=30DEC22".into(),
+            ))
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"category":"option","list":[{"symbol":"BTC-30DEC22-18000-C","bid1Price":"0","bid1Size":"0","bid1Iv":"0","ask1Price":"435","ask1Size":"0.66","ask1Iv":"5","lastPrice":"435","highPrice24h":"435","lowPrice24h":"165","markPrice":"0.00000009","indexPrice":"16600.55","markIv":"0.7567","underlyingPrice":"16590.42","openInterest":"6.3","turnover24h":"2482.73","volume24h":"0.15","totalVolume":"99","totalTurnover":"1967653","delta":"0.00000001","gamma":"0.00000001","vega":"0.00000004","theta":"-0.00000152","predictedDeliveryPrice":"0","change24h":"86"}]},"retExtInfo":{},"time":1672376592395}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        let tickers = client
+            .get_option_tickers("BTC", Some("30DEC22"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        let greeks = tickers.list[0].greeks.as_ref().unwrap();
+        assert_eq!(greeks.delta, "0.00000001");
+        assert_eq!(greeks.vega_f64().unwrap(), 0.00000004);
+    }
+
+    #[tokio::test]
+    async fn test_get_insurance() {
+        use crate::client::BybitClient;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/insurance")
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"updatedTime":"1714003200000","list":[{"coin":"USDT","symbols":"","balance":"963003013.88953","value":"962903613.56"},{"coin":"BTC","symbols":"","balance":"3502.15234201","value":"223016394.21"},{"coin":"USDT","symbols":"MERLUSDT,10000000AIDOGEUSDT","balance":"1000098.25","value":"999997.56"}]},"retExtInfo":{},"time":1714058314536}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        let insurance = client.get_insurance(None).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(insurance.updated_time, "1714003200000");
+        assert_eq!(insurance.list.len(), 3);
+        assert_eq!(insurance.list[1].coin, "BTC");
+        assert_eq!(insurance.list[1].balance_f64().unwrap(), 3502.15234201);
+        assert_eq!(insurance.list[2].symbols, "MERLUSDT,10000000AIDOGEUSDT");
+        assert_eq!(insurance.list[2].value, "999997.56");
+    }
+
+    #[tokio::test]
+    async fn test_get_delivery_price_rejects_spot() {
+        use crate::client::BybitClient;
+        use crate::error::BybitError;
+
+        let client = BybitClient::new("http://127.0.0.1:9".to_string());
+        let result = client
+            .get_delivery_price("spot", Some("BTCUSDT"), None, None, None)
+            .await;
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_kline_typed_sends_wire_interval() {
+        use crate::client::BybitClient;
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/kline")
+            .match_query(Matcher::Exact(
+                "category=spot&symbol=BTCUSDT&interval=D".into(),
+            ))
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"symbol":"BTCUSDT","category":"spot","list":[]},"retExtInfo":{},"time":1672025956592}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        let klines = client
+            .get_kline_typed("spot", "BTCUSDT", crate::types::Interval::Day, None, None)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert!(klines.list.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_recent_trades() {
+        use crate::client::BybitClient;
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/recent-trade")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("category".into(), "spot".into()),
+                Matcher::UrlEncoded("symbol".into(), "BTCUSDT".into()),
+                Matcher::UrlEncoded("limit".into(), "1".into()),
+            ]))
+            .with_body(
+                r#"{
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {
+                        "category": "spot",
+                        "list": [{
+                            "execId": "2100000000007764263",
+                            "symbol": "BTCUSDT",
+                            "price": "16618.49",
+                            "size": "0.00012",
+                            "side": "Buy",
+                            "time": "1672052955758",
+                            "isBlockTrade": false
+                        }]
+                    },
+                    "retExtInfo": {},
+                    "time": 1672053054358
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        let trades = client
+            .get_recent_trades("spot", "BTCUSDT", Some(1))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(trades.list.len(), 1);
+        assert_eq!(trades.list[0].exec_id, "2100000000007764263");
+        assert_eq!(trades.list[0].price, "16618.49");
+        assert!(!trades.list[0].is_block_trade);
+    }
+
+    #[tokio::test]
+    async fn test_get_recent_trades_rejects_limit_over_max() {
+        use crate::client::BybitClient;
+        use crate::error::BybitError;
+
+        let client = BybitClient::new("http://127.0.0.1:9".to_string());
+        let result = client.get_recent_trades("spot", "BTCUSDT", Some(61)).await;
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+
+        let result = client.get_recent_trades("linear", "BTCUSDT", Some(0)).await;
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_server_time_with_meta() {
+        use crate::auth::get_current_timestamp_ms;
+        use crate::client::BybitClient;
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/market/time")
+            .with_body(
+                r#"{"retCode":0,"retMsg":"OK","result":{"timeSecond":"1688639403","timeNano":"1688639403423213947"},"retExtInfo":{},"time":1688639403423}"#,
+            )
+            .create_async()
+            .await;
+
+        let before = get_current_timestamp_ms();
+        let client = BybitClient::new(server.url());
+        let (time, meta) = client.get_server_time_with_meta().await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(time.time_second, "1688639403");
+        assert_eq!(meta.server_time_ms, 1688639403423);
+        assert!(meta.local_send_ms >= before);
+        assert!(meta.local_receive_ms >= meta.local_send_ms);
+        assert!(meta.latency_ms as i64 <= meta.local_receive_ms - meta.local_send_ms + 1);
+        assert_eq!(meta.clock_skew_ms, 1688639403423 - meta.local_receive_ms);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_category_fails_before_request() {
+        use crate::client::BybitClient;
+        use crate::error::BybitError;
+
+        let client = BybitClient::new("http://127.0.0.1:9".to_string());
+        let result = client.get_tickers("linaer").await;
+        assert!(matches!(result, Err(BybitError::InvalidEnumValue { .. })));
+    }
 }
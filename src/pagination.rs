@@ -0,0 +1,277 @@
+//! Cursor pagination over list endpoints
+//!
+//! Bybit list endpoints return one page at a time together with a
+//! `nextPageCursor`. [`paginate`] turns a page fetcher into a
+//! [`futures_util::Stream`] of items that requests the next page only once the
+//! current one has been consumed.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use futures_util::StreamExt;
+//! use rusty_bybit::BybitClient;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = BybitClient::testnet()
+//!         .with_credentials("api_key".to_string(), "api_secret".to_string());
+//!
+//!     let orders = client.get_order_history_paged("linear", Some("BTCUSDT"), None, Some(50));
+//!     futures_util::pin_mut!(orders);
+//!     while let Some(order) = orders.next().await {
+//!         println!("{}", order.unwrap().order_id);
+//!     }
+//! }
+//! ```
+
+use std::collections::{HashSet, VecDeque};
+use std::future::Future;
+
+use futures_util::Stream;
+
+use crate::error::Result;
+use crate::types::{
+    BorrowHistoryList, ClosedPnl, ClosedPnlList, DeliveryPriceList, DepositRecordList, Execution,
+    ExecutionList, InstrumentList, Order, OrderList, Position, PositionList, RiskLimitList,
+    TickerList, TransactionLog, TransactionLogList, TransferRecordList, WithdrawRecordList,
+};
+
+/// A list response that may continue on another page
+///
+/// Bybit v5 list endpoints only report the cursor of the next page, not a
+/// total record count, so whether more records exist is all that is known
+/// before the cursor is drained.
+pub trait Paginated {
+    /// Cursor of the next page, `None` on the last page
+    fn cursor(&self) -> Option<&str>;
+
+    /// Whether another page follows this one
+    fn has_more(&self) -> bool {
+        self.cursor().is_some()
+    }
+}
+
+macro_rules! impl_paginated {
+    ($($list:ty),* $(,)?) => {
+        $(
+            impl Paginated for $list {
+                fn cursor(&self) -> Option<&str> {
+                    self.next_page_cursor.as_deref().filter(|c| !c.is_empty())
+                }
+            }
+        )*
+    };
+}
+
+impl_paginated!(
+    TickerList,
+    InstrumentList,
+    DeliveryPriceList,
+    RiskLimitList,
+    PositionList,
+    ExecutionList,
+    ClosedPnlList,
+    TransactionLogList,
+    BorrowHistoryList,
+    TransferRecordList,
+    WithdrawRecordList,
+    DepositRecordList,
+    OrderList,
+);
+
+/// A page of a list response: its items plus the cursor of the next page
+pub trait Page {
+    type Item;
+
+    fn into_parts(self) -> (Vec<Self::Item>, Option<String>);
+}
+
+macro_rules! impl_page {
+    ($($list:ty => $item:ty;)*) => {
+        $(
+            impl Page for $list {
+                type Item = $item;
+
+                fn into_parts(self) -> (Vec<Self::Item>, Option<String>) {
+                    (self.list, self.next_page_cursor)
+                }
+            }
+        )*
+    };
+}
+
+impl_page! {
+    OrderList => Order;
+    ExecutionList => Execution;
+    ClosedPnlList => ClosedPnl;
+    PositionList => Position;
+    TransactionLogList => TransactionLog;
+}
+
+/// Streams every item of a paginated endpoint
+///
+/// `fetch` is called with `None` for the first page and with the previous
+/// page's cursor afterwards, until the cursor comes back empty or repeats one
+/// already requested, which would otherwise loop forever. An error is yielded
+/// once and ends the stream.
+pub fn paginate<P, F, Fut>(fetch: F) -> impl Stream<Item = Result<P::Item>>
+where
+    P: Page,
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<P>>,
+{
+    let state = (
+        fetch,
+        VecDeque::new(),
+        None::<String>,
+        HashSet::new(),
+        false,
+    );
+    futures_util::stream::unfold(
+        state,
+        |(mut fetch, mut buffer, mut cursor, mut seen, mut done)| async move {
+            loop {
+                if let Some(item) = buffer.pop_front() {
+                    return Some((Ok(item), (fetch, buffer, cursor, seen, done)));
+                }
+                if done {
+                    return None;
+                }
+
+                if let Some(c) = &cursor {
+                    seen.insert(c.clone());
+                }
+                match fetch(cursor.take()).await {
+                    Ok(page) => {
+                        let (items, next) = page.into_parts();
+                        buffer.extend(items);
+                        cursor = next.filter(|c| !c.is_empty() && !seen.contains(c));
+                        done = cursor.is_none();
+                    }
+                    Err(e) => return Some((Err(e), (fetch, buffer, None, seen, true))),
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::BybitError;
+    use futures_util::StreamExt;
+
+    struct TestPage(Vec<u32>, Option<&'static str>);
+
+    impl Page for TestPage {
+        type Item = u32;
+
+        fn into_parts(self) -> (Vec<u32>, Option<String>) {
+            (self.0, self.1.map(str::to_string))
+        }
+    }
+
+    /// Deserializes `T` from a page carrying `cursor` and reports `has_more()`
+    fn has_more_after_decoding<T: Paginated + serde::de::DeserializeOwned>(cursor: &str) -> bool {
+        let json = serde_json::json!({
+            "category": "linear",
+            "list": [],
+            "rows": [],
+            "nextPageCursor": cursor,
+        });
+        let page: T = serde_json::from_value(json).unwrap();
+        assert_eq!(page.cursor(), Some(cursor).filter(|c| !c.is_empty()));
+        page.has_more()
+    }
+
+    #[test]
+    fn test_has_more_after_decoding_every_list() {
+        macro_rules! check {
+            ($($list:ty),*) => {
+                $(
+                    assert!(has_more_after_decoding::<$list>("abc"), stringify!($list));
+                    assert!(!has_more_after_decoding::<$list>(""), stringify!($list));
+                )*
+            };
+        }
+
+        check!(
+            TickerList,
+            InstrumentList,
+            DeliveryPriceList,
+            RiskLimitList,
+            PositionList,
+            ExecutionList,
+            ClosedPnlList,
+            TransactionLogList,
+            BorrowHistoryList,
+            TransferRecordList,
+            WithdrawRecordList,
+            DepositRecordList,
+            OrderList
+        );
+    }
+
+    #[tokio::test]
+    async fn test_follows_cursor_until_empty() {
+        let mut requested = Vec::new();
+        let items: Vec<u32> = paginate(|cursor: Option<String>| {
+            requested.push(cursor.clone());
+            async move {
+                Ok(match cursor.as_deref() {
+                    None => TestPage(vec![1, 2], Some("b")),
+                    Some("b") => TestPage(vec![], Some("c")),
+                    Some("c") => TestPage(vec![3], Some("")),
+                    other => panic!("unexpected cursor {:?}", other),
+                })
+            }
+        })
+        .map(|item| item.unwrap())
+        .collect()
+        .await;
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(
+            requested,
+            vec![None, Some("b".to_string()), Some("c".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stops_on_repeated_cursor() {
+        let mut calls = 0;
+        let items: Vec<u32> = paginate(|cursor: Option<String>| {
+            calls += 1;
+            async move {
+                Ok(match cursor.as_deref() {
+                    None => TestPage(vec![1], Some("b")),
+                    Some("b") => TestPage(vec![2], Some("c")),
+                    Some("c") => TestPage(vec![3], Some("b")),
+                    other => panic!("unexpected cursor {:?}", other),
+                })
+            }
+        })
+        .map(|item| item.unwrap())
+        .collect()
+        .await;
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test]
+    async fn test_error_ends_stream() {
+        let results: Vec<Result<u32>> = paginate(|cursor: Option<String>| async move {
+            match cursor {
+                None => Ok(TestPage(vec![1], Some("b"))),
+                Some(_) => Err(BybitError::InvalidParameter("boom".to_string())),
+            }
+        })
+        .collect()
+        .await;
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap(), &1);
+        assert!(matches!(results[1], Err(BybitError::InvalidParameter(_))));
+    }
+}
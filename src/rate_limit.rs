@@ -0,0 +1,104 @@
+//! Client-side token-bucket rate limiting
+//!
+//! Keeps request bursts under Bybit's per-second limits so callers don't run into
+//! `10006` responses. The bucket holds up to one second's worth of tokens and
+//! refills continuously.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    requests_per_second: u32,
+    state: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(requests_per_second: u32) -> Self {
+        let requests_per_second = requests_per_second.max(1);
+        Self {
+            requests_per_second,
+            state: Mutex::new(Bucket {
+                tokens: requests_per_second as f64,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a token is available and takes it
+    pub(crate) async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.lock();
+                self.refill(&mut bucket);
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / self.requests_per_second as f64)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Whole tokens currently available without waiting
+    pub(crate) fn available_permits(&self) -> u32 {
+        let mut bucket = self.lock();
+        self.refill(&mut bucket);
+        bucket.tokens as u32
+    }
+
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        let capacity = self.requests_per_second as f64;
+        bucket.tokens = (bucket.tokens + elapsed * capacity).min(capacity);
+        bucket.last_refill = now;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Bucket> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_burst_up_to_capacity_is_immediate() {
+        let limiter = RateLimiter::new(5);
+        assert_eq!(limiter.available_permits(), 5);
+
+        let start = Instant::now();
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(50));
+        assert_eq!(limiter.available_permits(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_request_beyond_capacity_waits_for_refill() {
+        let limiter = RateLimiter::new(10);
+        for _ in 0..10 {
+            limiter.acquire().await;
+        }
+
+        let start = Instant::now();
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(80));
+    }
+
+    #[test]
+    fn test_zero_rate_is_clamped() {
+        let limiter = RateLimiter::new(0);
+        assert_eq!(limiter.available_permits(), 1);
+    }
+}
@@ -0,0 +1,18 @@
+//! Shared fixtures for unit tests
+
+/// Body of a successful response carrying one page of `list`, as returned by
+/// Bybit's cursor-paginated endpoints
+pub(crate) fn page_body(list: Vec<serde_json::Value>, next_page_cursor: &str) -> String {
+    serde_json::json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {
+            "category": "linear",
+            "list": list,
+            "nextPageCursor": next_page_cursor,
+        },
+        "retExtInfo": {},
+        "time": 1672221263862_i64,
+    })
+    .to_string()
+}
@@ -4,7 +4,7 @@
 //!
 //! # Example
 //!
-//! ````rust,no_run
+//! ```rust,no_run
 //! use rusty_bybit::BybitClient;
 //! use rusty_bybit::CreateOrderRequest;
 //!
@@ -24,23 +24,61 @@
 //!     println!("Order ID: {}", response.order_id);
 //! }
 //! ```
+//!
+//! The request builder also accepts the typed enums; passing them instead of
+//! strings rules out misspelled sides and order types:
+//!
+//! ```rust
+//! use rusty_bybit::CreateOrderRequest;
+//! use rusty_bybit::types::{OrderType, Side, TimeInForce};
+//!
+//! let request = CreateOrderRequest::builder()
+//!     .symbol("BTCUSDT")
+//!     .side(Side::Buy)
+//!     .order_type(OrderType::Limit)
+//!     .time_in_force(TimeInForce::PostOnly)
+//!     .qty("0.001")
+//!     .price("28000")
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(request.side, "Buy");
+//! ```
 
 use crate::client::BybitClient;
-use crate::error::Result;
-use crate::types::{CreateOrderRequest, CreateOrderResponse, OrderList};
+use crate::error::{BybitError, Result};
+use crate::pagination::paginate;
+use crate::types::{
+    AmendOrderRequest, BatchOrderResponse, CancelAllResult, CancelOrderSpec, Category,
+    CreateOrderRequest, CreateOrderResponse, Order, OrderList,
+};
+use futures_util::{Stream, TryStreamExt};
 
 impl BybitClient {
+    /// Places an order; see [`with_auto_order_link_id`](Self::with_auto_order_link_id)
+    /// for making retried creates idempotent
     pub async fn create_order(&self, request: &CreateOrderRequest) -> Result<CreateOrderResponse> {
-        let body = serde_json::to_value(request)?;
+        let mut body = serde_json::to_value(request)?;
+        if self.auto_order_link_id() && request.order_link_id.as_deref().is_none_or(str::is_empty) {
+            let order_link_id = uuid::Uuid::new_v4().to_string();
+            #[cfg(feature = "tracing")]
+            tracing::debug!(%order_link_id, "generated orderLinkId");
+            body["orderLinkId"] = order_link_id.into();
+        }
         self.post("/v5/order/create", Some(body)).await
     }
 
+    pub async fn amend_order(&self, request: &AmendOrderRequest) -> Result<CreateOrderResponse> {
+        let body = serde_json::to_value(request)?;
+        self.post("/v5/order/amend", Some(body)).await
+    }
+
     pub async fn cancel_order(
         &self,
-        category: &str,
+        category: impl AsRef<str>,
         order_id: &str,
         symbol: &str,
-    ) -> Result<serde_json::Value> {
+    ) -> Result<CreateOrderResponse> {
+        let category = Category::try_from(category.as_ref())?.as_str();
         let body = serde_json::json!({
             "category": category,
             "orderId": order_id,
@@ -49,31 +87,811 @@ impl BybitClient {
         self.post("/v5/order/cancel", Some(body)).await
     }
 
-    pub async fn cancel_all_orders(
+    /// Like [`cancel_order`](Self::cancel_order), identifying the order by its
+    /// client-assigned `order_link_id`
+    pub async fn cancel_order_by_link_id(
         &self,
-        category: &str,
+        category: impl AsRef<str>,
+        order_link_id: &str,
         symbol: &str,
-    ) -> Result<serde_json::Value> {
+    ) -> Result<CreateOrderResponse> {
+        let category = Category::try_from(category.as_ref())?.as_str();
         let body = serde_json::json!({
             "category": category,
+            "orderLinkId": order_link_id,
             "symbol": symbol,
         });
+        self.post("/v5/order/cancel", Some(body)).await
+    }
+
+    /// Cancels every open order matching the filters
+    ///
+    /// `linear` and `inverse` need at least one of `symbol`, `base_coin` or
+    /// `settle_coin`, so e.g. `settle_coin = Some("USDT")` cancels across all
+    /// USDT contracts. `order_filter` is `Order`, `StopOrder` or `tpslOrder`;
+    /// without it all kinds of orders are cancelled.
+    pub async fn cancel_all_orders(
+        &self,
+        category: impl AsRef<str>,
+        symbol: Option<&str>,
+        base_coin: Option<&str>,
+        settle_coin: Option<&str>,
+        order_filter: Option<&str>,
+    ) -> Result<CancelAllResult> {
+        let category = Category::try_from(category.as_ref())?;
+        if matches!(category, Category::Linear | Category::Inverse)
+            && symbol.is_none()
+            && base_coin.is_none()
+            && settle_coin.is_none()
+        {
+            return Err(BybitError::InvalidParameter(format!(
+                "{} cancel-all needs a symbol, base coin or settle coin",
+                category.as_str()
+            )));
+        }
+
+        let mut body = serde_json::json!({ "category": category.as_str() });
+        for (key, value) in [
+            ("symbol", symbol),
+            ("baseCoin", base_coin),
+            ("settleCoin", settle_coin),
+            ("orderFilter", order_filter),
+        ] {
+            if let Some(v) = value {
+                body[key] = v.into();
+            }
+        }
         self.post("/v5/order/cancel-all", Some(body)).await
     }
 
-    pub async fn get_order(&self, category: &str, order_id: &str) -> Result<OrderList> {
+    /// Places several orders in one request
+    ///
+    /// The batch-level `category` applies to every order, so each request's own
+    /// `category` is left out of the body. Individual orders can be rejected
+    /// while the request as a whole succeeds; check [`BatchOrderResponse::failures`].
+    /// Bybit accepts up to 10 orders per batch for spot and 20 for the other
+    /// categories.
+    pub async fn create_batch_orders(
+        &self,
+        category: impl AsRef<str>,
+        orders: &[CreateOrderRequest],
+    ) -> Result<BatchOrderResponse> {
+        let category = Category::try_from(category.as_ref())?;
+        check_batch_size(category, orders.len())?;
+        let request = orders
+            .iter()
+            .map(|order| {
+                let mut item = serde_json::to_value(order)?;
+                if let Some(fields) = item.as_object_mut() {
+                    fields.remove("category");
+                }
+                Ok(item)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let body = serde_json::json!({
+            "category": category.as_str(),
+            "request": request,
+        });
+        self.post_batch("/v5/order/create-batch", body).await
+    }
+
+    /// Cancels several orders in one request
+    ///
+    /// Individual cancels can fail while the request as a whole succeeds; check
+    /// [`BatchOrderResponse::failures`]. Bybit accepts up to 10 orders per batch
+    /// for spot and 20 for the other categories.
+    pub async fn cancel_batch_orders(
+        &self,
+        category: impl AsRef<str>,
+        orders: Vec<CancelOrderSpec>,
+    ) -> Result<BatchOrderResponse> {
+        let category = Category::try_from(category.as_ref())?;
+        check_batch_size(category, orders.len())?;
+        let body = serde_json::json!({
+            "category": category.as_str(),
+            "request": orders,
+        });
+        self.post_batch("/v5/order/cancel-batch", body).await
+    }
+
+    async fn post_batch(&self, path: &str, body: serde_json::Value) -> Result<BatchOrderResponse> {
+        let response = self.post_response::<BatchOrderResponse>(path, body).await?;
+        let ext_info = response.ext_info_list();
+        Ok(BatchOrderResponse {
+            ext_info,
+            ..response.result
+        })
+    }
+
+    pub async fn get_order(&self, category: impl AsRef<str>, order_id: &str) -> Result<OrderList> {
+        let category = Category::try_from(category.as_ref())?.as_str();
         let query = vec![("category", category), ("orderId", order_id)];
         self.get("/v5/order/realtime", Some(query)).await
     }
 
-    pub async fn get_open_orders(&self, category: &str) -> Result<OrderList> {
+    /// Like [`get_order`](Self::get_order), looking the order up by its
+    /// client-assigned `order_link_id`
+    pub async fn get_order_by_link_id(
+        &self,
+        category: impl AsRef<str>,
+        order_link_id: &str,
+    ) -> Result<OrderList> {
+        let category = Category::try_from(category.as_ref())?.as_str();
+        let query = vec![("category", category), ("orderLinkId", order_link_id)];
+        self.get("/v5/order/realtime", Some(query)).await
+    }
+
+    /// Past orders, newest first. Pass the previous page's `next_page_cursor`
+    /// as `cursor` to page through the history.
+    pub async fn get_order_history(
+        &self,
+        category: impl AsRef<str>,
+        symbol: Option<&str>,
+        order_status: Option<&str>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<OrderList> {
+        let category = Category::try_from(category.as_ref())?.as_str();
+        let limit = limit.map(|l| l.to_string());
+
+        let mut query = vec![("category", category)];
+        if let Some(s) = symbol {
+            query.push(("symbol", s));
+        }
+        if let Some(s) = order_status {
+            query.push(("orderStatus", s));
+        }
+        if let Some(l) = &limit {
+            query.push(("limit", l.as_str()));
+        }
+        if let Some(c) = cursor {
+            query.push(("cursor", c));
+        }
+        self.get("/v5/order/history", Some(query)).await
+    }
+
+    /// Streams the order history, following `nextPageCursor` until the last
+    /// page; `page_size` is passed as `limit` on every request
+    pub fn get_order_history_paged<'a>(
+        &'a self,
+        category: impl AsRef<str>,
+        symbol: Option<&'a str>,
+        order_status: Option<&'a str>,
+        page_size: Option<u32>,
+    ) -> impl Stream<Item = Result<Order>> + 'a {
+        let category = category.as_ref().to_string();
+        paginate(move |cursor| {
+            let category = category.clone();
+            async move {
+                self.get_order_history(category, symbol, order_status, page_size, cursor.as_deref())
+                    .await
+            }
+        })
+    }
+
+    pub async fn get_open_orders(&self, category: impl AsRef<str>) -> Result<OrderList> {
+        let category = Category::try_from(category.as_ref())?.as_str();
         let query = vec![("category", category)];
         self.get("/v5/order/realtime", Some(query)).await
     }
+
+    /// Every open order in `category`, following `nextPageCursor` across pages
+    /// of 50
+    pub async fn get_all_open_orders(&self, category: impl AsRef<str>) -> Result<Vec<Order>> {
+        let category = Category::try_from(category.as_ref())?.as_str();
+        paginate(|cursor: Option<String>| async move {
+            let mut query = vec![("category", category), ("limit", "50")];
+            if let Some(c) = &cursor {
+                query.push(("cursor", c.as_str()));
+            }
+            self.get::<OrderList>("/v5/order/realtime", Some(query))
+                .await
+        })
+        .try_collect()
+        .await
+    }
+}
+
+fn check_batch_size(category: Category, len: usize) -> Result<()> {
+    let max = match category {
+        Category::Spot => 10,
+        _ => 20,
+    };
+    if len == 0 || len > max {
+        return Err(BybitError::InvalidParameter(format!(
+            "batch for {} must contain 1 to {} orders, got {}",
+            category.as_str(),
+            max,
+            len
+        )));
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use mockito::Matcher;
+    use std::sync::{Arc, Mutex};
+
     #[test]
     fn test_trade_module_exists() {}
+
+    fn limit_order(order_link_id: Option<&str>) -> CreateOrderRequest {
+        let builder = CreateOrderRequest::builder()
+            .symbol("BTCUSDT")
+            .side("Buy")
+            .order_type("Limit")
+            .qty("0.001")
+            .price("20000");
+        match order_link_id {
+            Some(id) => builder.order_link_id(id),
+            None => builder,
+        }
+        .build()
+        .unwrap()
+    }
+
+    /// Echoes the request's `orderLinkId` back and records it in `seen`
+    fn echo_order_link_id(
+        seen: Arc<Mutex<Vec<String>>>,
+    ) -> impl Fn(&mockito::Request) -> Vec<u8> + Send + Sync + 'static {
+        move |request| {
+            let body: serde_json::Value = serde_json::from_slice(request.body().unwrap()).unwrap();
+            let id = body["orderLinkId"].as_str().unwrap_or_default().to_string();
+            seen.lock().unwrap().push(id.clone());
+            serde_json::json!({
+                "retCode": 0,
+                "retMsg": "OK",
+                "result": { "orderId": "1321003749386327552", "orderLinkId": id },
+                "retExtInfo": {},
+                "time": 1672211918471i64,
+            })
+            .to_string()
+            .into_bytes()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_order_generates_order_link_id() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/order/create")
+            .with_body_from_request(echo_order_link_id(seen.clone()))
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url())
+            .with_credentials("key".into(), "secret".into())
+            .with_auto_order_link_id(true);
+        let first = client.create_order(&limit_order(None)).await.unwrap();
+        let second = client.create_order(&limit_order(Some(""))).await.unwrap();
+
+        mock.assert_async().await;
+        assert!(uuid::Uuid::parse_str(&first.order_link_id).is_ok());
+        assert!(uuid::Uuid::parse_str(&second.order_link_id).is_ok());
+        assert_ne!(first.order_link_id, second.order_link_id);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![first.order_link_id, second.order_link_id]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_order_keeps_caller_order_link_id() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/v5/order/create")
+            .with_body_from_request(echo_order_link_id(seen.clone()))
+            .expect(2)
+            .create_async()
+            .await;
+
+        let enabled = BybitClient::new(server.url())
+            .with_credentials("key".into(), "secret".into())
+            .with_auto_order_link_id(true);
+        enabled
+            .create_order(&limit_order(Some("my-order-1")))
+            .await
+            .unwrap();
+        let disabled =
+            BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        disabled.create_order(&limit_order(None)).await.unwrap();
+
+        assert_eq!(*seen.lock().unwrap(), vec!["my-order-1", ""]);
+    }
+
+    #[tokio::test]
+    async fn test_create_order_retry_reuses_generated_order_link_id() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut server = mockito::Server::new_async().await;
+        let failing = {
+            let seen = seen.clone();
+            server
+                .mock("POST", "/v5/order/create")
+                .with_body_from_request(move |request| {
+                    let body: serde_json::Value =
+                        serde_json::from_slice(request.body().unwrap()).unwrap();
+                    seen.lock()
+                        .unwrap()
+                        .push(body["orderLinkId"].as_str().unwrap().to_string());
+                    br#"{"retCode":10006,"retMsg":"Too many visits!","result":{},"retExtInfo":{},"time":1}"#.to_vec()
+                })
+                .expect(1)
+                .create_async()
+                .await
+        };
+        let succeeding = server
+            .mock("POST", "/v5/order/create")
+            .with_body_from_request(echo_order_link_id(seen.clone()))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url())
+            .with_credentials("key".into(), "secret".into())
+            .with_auto_order_link_id(true)
+            .with_retry(crate::RetryConfig {
+                max_attempts: 2,
+                base_delay: std::time::Duration::from_millis(1),
+                max_delay: std::time::Duration::from_millis(5),
+            });
+        let response = client.create_order(&limit_order(None)).await.unwrap();
+
+        failing.assert_async().await;
+        succeeding.assert_async().await;
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[0], seen[1]);
+        assert_eq!(seen[1], response.order_link_id);
+    }
+
+    #[tokio::test]
+    async fn test_amend_order_omits_unset_fields() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/order/amend")
+            .match_body(Matcher::Json(serde_json::json!({
+                "category": "linear",
+                "symbol": "BTCUSDT",
+                "orderId": "c6f055d9-7f21-4079-913d-e6523a9cfffa",
+                "price": "28100",
+            })))
+            .with_body(
+                r#"{"retCode":0,"retMsg":"OK","result":{"orderId":"c6f055d9-7f21-4079-913d-e6523a9cfffa","orderLinkId":"linear-004"},"retExtInfo":{},"time":1672217093461}"#,
+            )
+            .create_async()
+            .await;
+
+        let request = AmendOrderRequest::builder()
+            .symbol("BTCUSDT")
+            .order_id("c6f055d9-7f21-4079-913d-e6523a9cfffa")
+            .price("28100")
+            .build()
+            .unwrap();
+
+        let client = BybitClient::new(server.url());
+        let response = client.amend_order(&request).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(response.order_id, "c6f055d9-7f21-4079-913d-e6523a9cfffa");
+        assert_eq!(response.order_link_id, "linear-004");
+    }
+
+    #[tokio::test]
+    async fn test_create_batch_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/order/create-batch")
+            .match_body(Matcher::Json(serde_json::json!({
+                "category": "linear",
+                "request": [
+                    {
+                        "symbol": "BTCUSDT",
+                        "side": "Buy",
+                        "orderType": "Limit",
+                        "qty": "0.001",
+                        "price": "28000",
+                        "timeInForce": "PostOnly",
+                        "orderLinkId": "grid-1",
+                    },
+                    {
+                        "symbol": "BTCUSDT",
+                        "side": "Sell",
+                        "orderType": "Limit",
+                        "qty": "0.001",
+                        "price": "32000",
+                        "timeInForce": "PostOnly",
+                        "orderLinkId": "grid-2",
+                    },
+                ],
+            })))
+            .with_body(
+                r#"{
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {
+                        "list": [
+                            {"category": "linear", "symbol": "BTCUSDT", "orderId": "b4b3e1a1", "orderLinkId": "grid-1", "createAt": "1713434102753"},
+                            {"category": "linear", "symbol": "BTCUSDT", "orderId": "", "orderLinkId": "grid-2", "createAt": ""}
+                        ]
+                    },
+                    "retExtInfo": {
+                        "list": [
+                            {"code": 0, "msg": "OK"},
+                            {"code": 110007, "msg": "Insufficient available balance"}
+                        ]
+                    },
+                    "time": 1713434102752
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let order = |side: &str, price: &str, link_id: &str| {
+            CreateOrderRequest::builder()
+                .symbol("BTCUSDT")
+                .side(side)
+                .order_type("Limit")
+                .qty("0.001")
+                .price(price)
+                .time_in_force("PostOnly")
+                .order_link_id(link_id)
+                .build()
+                .unwrap()
+        };
+        let orders = [
+            order("Buy", "28000", "grid-1"),
+            order("Sell", "32000", "grid-2"),
+        ];
+
+        let client = BybitClient::new(server.url());
+        let response = client.create_batch_orders("linear", &orders).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(response.list[0].order_id, "b4b3e1a1");
+        let failures: Vec<_> = response.failures().collect();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].1.code, 110007);
+    }
+
+    #[tokio::test]
+    async fn test_create_batch_orders_rejects_empty_batch() {
+        let client = BybitClient::new("http://127.0.0.1:9".to_string());
+        let result = client.create_batch_orders("linear", &[]).await;
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_batch_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/order/cancel-batch")
+            .match_body(Matcher::Json(serde_json::json!({
+                "category": "spot",
+                "request": [
+                    { "symbol": "BTCUSDT", "orderId": "1666800494330512128" },
+                    { "symbol": "ATOMUSDT", "orderLinkId": "1666800494330512129" },
+                ],
+            })))
+            .with_body(
+                r#"{
+                    "retCode": 0,
+                    "retMsg": "success",
+                    "result": {
+                        "list": [
+                            {"category": "spot", "symbol": "BTCUSDT", "orderId": "1666800494330512128", "orderLinkId": "spot-btc-03"},
+                            {"category": "spot", "symbol": "ATOMUSDT", "orderId": "", "orderLinkId": ""}
+                        ]
+                    },
+                    "retExtInfo": {
+                        "list": [
+                            {"code": 0, "msg": "OK"},
+                            {"code": 170213, "msg": "Order does not exist."}
+                        ]
+                    },
+                    "time": 1713434299047
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        let response = client
+            .cancel_batch_orders(
+                Category::Spot,
+                vec![
+                    CancelOrderSpec::by_order_id("BTCUSDT", "1666800494330512128"),
+                    CancelOrderSpec::by_order_link_id("ATOMUSDT", "1666800494330512129"),
+                ],
+            )
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(response.list.len(), 2);
+        assert_eq!(response.list[0].order_id, "1666800494330512128");
+        let failures: Vec<_> = response.failures().collect();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, 1);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_orders_by_settle_coin() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/order/cancel-all")
+            .match_body(Matcher::Json(serde_json::json!({
+                "category": "linear",
+                "settleCoin": "USDT",
+                "orderFilter": "StopOrder",
+            })))
+            .with_body(
+                r#"{
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {
+                        "list": [
+                            {"orderId": "1616024329462743808", "orderLinkId": "1616024329462743809"},
+                            {"orderId": "1616024287544869632", "orderLinkId": "1616024287544869633"}
+                        ],
+                        "success": "1"
+                    },
+                    "retExtInfo": {},
+                    "time": 1707381118116
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let result = client
+            .cancel_all_orders("linear", None, None, Some("USDT"), Some("StopOrder"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(
+            result.order_ids().collect::<Vec<_>>(),
+            vec!["1616024329462743808", "1616024287544869632"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_orders_requires_scope_for_derivatives() {
+        let client = BybitClient::new("http://127.0.0.1:9".to_string());
+
+        let result = client
+            .cancel_all_orders("linear", None, None, None, None)
+            .await;
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_batch_orders_rejects_oversized_batch() {
+        let client = BybitClient::new("http://127.0.0.1:9".to_string());
+        let orders = (0..11)
+            .map(|i| CancelOrderSpec::by_order_id("BTCUSDT", i.to_string()))
+            .collect();
+
+        let result = client.cancel_batch_orders("spot", orders).await;
+        assert!(matches!(result, Err(BybitError::InvalidParameter(_))));
+    }
+
+    /// Response body for one page of orders with the given ids
+    fn order_page(ids: &[&str], cursor: &str) -> String {
+        let list: Vec<_> = ids
+            .iter()
+            .map(|id| {
+                serde_json::json!({
+                    "orderId": id, "orderLinkId": "", "symbol": "BTCUSDT", "side": "Buy",
+                    "orderType": "Limit", "price": "28000", "qty": "0.001",
+                    "timeInForce": "GTC", "createType": "CreateByUser",
+                    "cancelType": "UNKNOWN", "orderStatus": "Filled", "leavesQty": "0",
+                    "cumExecQty": "0.001", "avgPrice": "28000",
+                    "createdTime": "1672221263407", "updatedTime": "1672221263407",
+                    "positionIdx": 0,
+                })
+            })
+            .collect();
+        crate::test_support::page_body(list, cursor)
+    }
+
+    #[tokio::test]
+    async fn test_get_order_history_paged() {
+        use futures_util::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+        let first = server
+            .mock("GET", "/v5/order/history")
+            .match_query(Matcher::Exact("category=linear&limit=2".into()))
+            .with_body(order_page(&["1", "2"], "page-2"))
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/v5/order/history")
+            .match_query(Matcher::Exact(
+                "category=linear&limit=2&cursor=page-2".into(),
+            ))
+            .with_body(order_page(&["3"], ""))
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let ids: Vec<String> = client
+            .get_order_history_paged("linear", None, None, Some(2))
+            .map(|order| order.unwrap().order_id)
+            .collect()
+            .await;
+
+        first.assert_async().await;
+        second.assert_async().await;
+        assert_eq!(ids, vec!["1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_open_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let first = server
+            .mock("GET", "/v5/order/realtime")
+            .match_query(Matcher::Exact("category=linear&limit=50".into()))
+            .with_body(order_page(&["1", "2"], "page-2"))
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/v5/order/realtime")
+            .match_query(Matcher::Exact(
+                "category=linear&limit=50&cursor=page-2".into(),
+            ))
+            .with_body(order_page(&["3"], ""))
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let orders = client.get_all_open_orders("linear").await.unwrap();
+
+        first.assert_async().await;
+        second.assert_async().await;
+        let ids: Vec<_> = orders.iter().map(|o| o.order_id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_order_by_link_id() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v5/order/cancel")
+            .match_body(Matcher::Json(serde_json::json!({
+                "category": "linear",
+                "orderLinkId": "my-order-1",
+                "symbol": "BTCUSDT",
+            })))
+            .with_body(r#"{"retCode":0,"retMsg":"OK","result":{"orderId":"c6f055d9-7f21-4079-913d-e6523a9cfffa","orderLinkId":"my-order-1"},"retExtInfo":{},"time":1672217377164}"#)
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let result = client
+            .cancel_order_by_link_id("linear", "my-order-1", "BTCUSDT")
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(result.order_id, "c6f055d9-7f21-4079-913d-e6523a9cfffa");
+        assert_eq!(result.order_link_id, "my-order-1");
+    }
+
+    #[tokio::test]
+    async fn test_get_order_by_link_id() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/order/realtime")
+            .match_query(Matcher::Exact(
+                "category=linear&orderLinkId=my-order-1".into(),
+            ))
+            .with_body(order_page(&["1"], ""))
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url()).with_credentials("key".into(), "secret".into());
+        let orders = client
+            .get_order_by_link_id("linear", "my-order-1")
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(orders.list[0].order_id, "1");
+    }
+
+    #[tokio::test]
+    async fn test_get_order_history() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/v5/order/history")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("category".into(), "linear".into()),
+                Matcher::UrlEncoded("symbol".into(), "ETHUSDT".into()),
+                Matcher::UrlEncoded("orderStatus".into(), "Filled".into()),
+                Matcher::UrlEncoded("limit".into(), "1".into()),
+                Matcher::UrlEncoded("cursor".into(), "page-1".into()),
+            ]))
+            .with_body(
+                r#"{
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {
+                        "list": [{
+                            "orderId": "14bad3a1-6454-43d8-bcf2-5345519ef6a2",
+                            "orderLinkId": "",
+                            "blockTradeId": "",
+                            "symbol": "ETHUSDT",
+                            "price": "2116.17",
+                            "qty": "0.01",
+                            "side": "Sell",
+                            "isLeverage": "",
+                            "positionIdx": 0,
+                            "orderStatus": "Filled",
+                            "cancelType": "UNKNOWN",
+                            "rejectReason": "EC_NoError",
+                            "avgPrice": "2227.55",
+                            "leavesQty": "0",
+                            "leavesValue": "0",
+                            "cumExecQty": "0.01",
+                            "cumExecValue": "22.2755",
+                            "cumExecFee": "0.01225153",
+                            "timeInForce": "IOC",
+                            "orderType": "Market",
+                            "stopOrderType": "",
+                            "orderIv": "",
+                            "triggerPrice": "",
+                            "takeProfit": "",
+                            "stopLoss": "",
+                            "tpTriggerBy": "",
+                            "slTriggerBy": "",
+                            "triggerDirection": 0,
+                            "triggerBy": "",
+                            "lastPriceOnCreated": "2227.55",
+                            "reduceOnly": false,
+                            "closeOnTrigger": false,
+                            "smpType": "None",
+                            "smpGroup": 0,
+                            "smpOrderId": "",
+                            "tpslMode": "",
+                            "tpLimitPrice": "",
+                            "slLimitPrice": "",
+                            "placeType": "",
+                            "createType": "CreateByUser",
+                            "createdTime": "1684476068369",
+                            "updatedTime": "1684476068372"
+                        }],
+                        "nextPageCursor": "page-2",
+                        "category": "linear"
+                    },
+                    "retExtInfo": {},
+                    "time": 1684766282976
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new(server.url());
+        let orders = client
+            .get_order_history(
+                Category::Linear,
+                Some("ETHUSDT"),
+                Some("Filled"),
+                Some(1),
+                Some("page-1"),
+            )
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(orders.list[0].status, "Filled");
+        assert_eq!(orders.list[0].avg_price, "2227.55");
+        assert_eq!(orders.next_page_cursor.as_deref(), Some("page-2"));
+    }
 }
@@ -10,8 +10,17 @@
 //! - `PositionList` - wraps `Vec<Position>`
 //! - `OrderList` - wraps `Vec<Order>`
 //! - `WalletBalance` - wraps `Vec<AccountBalance>`
+//! - `KlineList` - wraps `Vec<Kline>`
+//! - `RecentTradeList` - wraps `Vec<RecentTrade>`
+//! - `ExecutionList` - wraps `Vec<Execution>`
+//! - `ClosedPnlList` - wraps `Vec<ClosedPnl>`
+//! - `FeeRateList` - wraps `Vec<FeeRate>`
 
-use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::BybitError;
 
 /// Bybit server time response
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,9 +32,55 @@ pub struct ServerTime {
 }
 
 /// Empty result for API calls that don't return data
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Deserializes from `{}`, `null` or any object, ignoring its fields, and
+/// serializes as `{}`.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct EmptyResult;
 
+impl Serialize for EmptyResult {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        serializer.serialize_map(Some(0))?.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for EmptyResult {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EmptyResultVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for EmptyResultVisitor {
+            type Value = EmptyResult;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("an object or null")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                while map
+                    .next_entry::<serde::de::IgnoredAny, serde::de::IgnoredAny>()?
+                    .is_some()
+                {}
+                Ok(EmptyResult)
+            }
+
+            fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+                Ok(EmptyResult)
+            }
+
+            fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+                Ok(EmptyResult)
+            }
+        }
+
+        deserializer.deserialize_any(EmptyResultVisitor)
+    }
+}
+
 /// Product category for Bybit API endpoints
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum Category {
@@ -39,6 +94,60 @@ pub enum Category {
     Option,
 }
 
+/// The variant whose `#[serde(rename)]` is `value`
+fn from_wire_name<T: serde::de::DeserializeOwned>(
+    enum_name: &str,
+    value: &str,
+) -> Result<T, BybitError> {
+    serde_json::from_value(serde_json::Value::String(value.to_string())).map_err(|_| {
+        BybitError::InvalidEnumValue {
+            enum_name: enum_name.to_string(),
+            value: value.to_string(),
+        }
+    })
+}
+
+impl From<Category> for String {
+    fn from(category: Category) -> Self {
+        category.as_str().to_string()
+    }
+}
+
+impl Category {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Linear => "linear",
+            Category::Inverse => "inverse",
+            Category::Spot => "spot",
+            Category::Option => "option",
+        }
+    }
+}
+
+/// Lets endpoint methods take either a `Category` or its wire string
+impl AsRef<str> for Category {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl TryFrom<&str> for Category {
+    type Error = BybitError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "linear" => Ok(Category::Linear),
+            "inverse" => Ok(Category::Inverse),
+            "spot" => Ok(Category::Spot),
+            "option" => Ok(Category::Option),
+            _ => Err(BybitError::InvalidEnumValue {
+                enum_name: "Category".to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+}
+
 /// Bybit API response wrapper
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiResponse<T> {
@@ -52,6 +161,75 @@ pub struct ApiResponse<T> {
     pub time: i64,
 }
 
+/// Per-item outcome reported in `retExtInfo.list` by batch endpoints
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtInfoItem {
+    pub code: i32,
+    pub msg: String,
+}
+
+impl ExtInfoItem {
+    pub fn is_success(&self) -> bool {
+        self.code == 0
+    }
+}
+
+/// Typed `retExtInfo`; only batch endpoints fill `list`, other endpoints
+/// send `{}`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RetExtInfo {
+    #[serde(default)]
+    pub list: Vec<ExtInfoItem>,
+}
+
+impl RetExtInfo {
+    /// Whether no item was rejected; true when there are no items
+    pub fn all_succeeded(&self) -> bool {
+        self.list.iter().all(ExtInfoItem::is_success)
+    }
+}
+
+impl<T> ApiResponse<T> {
+    /// `retExtInfo` parsed into [`RetExtInfo`]
+    ///
+    /// Falls back to an empty [`RetExtInfo`] when `retExtInfo` is missing or
+    /// does not match the documented `{list: [{code, msg}]}` shape.
+    pub fn ext_info(&self) -> RetExtInfo {
+        RetExtInfo::deserialize(&self.ret_ext_info).unwrap_or_default()
+    }
+
+    /// Per-item results of a batch request, in request order
+    pub fn ext_info_list(&self) -> Vec<ExtInfoItem> {
+        self.ext_info().list
+    }
+}
+
+/// Rate-limit state reported by the `X-Bapi-Limit*` response headers
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitStatus {
+    /// Requests allowed per window for the endpoint
+    pub limit: u32,
+    /// Requests left in the current window
+    pub remaining: u32,
+    /// When the window resets, in milliseconds since the epoch
+    pub reset_timestamp_ms: u64,
+}
+
+/// Timing of a single REST call, for latency and clock-skew monitoring
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Meta {
+    /// Round-trip time, including any retries
+    pub latency_ms: u64,
+    /// Bybit's `time` field from the response
+    pub server_time_ms: i64,
+    /// Local clock when the request was sent
+    pub local_send_ms: i64,
+    /// Local clock when the response was received
+    pub local_receive_ms: i64,
+    /// `server_time_ms - local_receive_ms`; positive when the local clock is behind
+    pub clock_skew_ms: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderBook {
     pub b: Vec<(String, String)>,
@@ -60,6 +238,75 @@ pub struct OrderBook {
     pub u: i64,
 }
 
+impl OrderBook {
+    /// Highest bid as `(price, size)`, or `None` if the side is empty or unparseable
+    pub fn best_bid(&self) -> Option<(f64, f64)> {
+        self.b.first().and_then(parse_level)
+    }
+
+    /// Lowest ask as `(price, size)`, or `None` if the side is empty or unparseable
+    pub fn best_ask(&self) -> Option<(f64, f64)> {
+        self.a.first().and_then(parse_level)
+    }
+
+    pub fn mid_price(&self) -> Option<f64> {
+        let (bid, _) = self.best_bid()?;
+        let (ask, _) = self.best_ask()?;
+        Some((bid + ask) / 2.0)
+    }
+
+    /// Best ask minus best bid
+    pub fn spread(&self) -> Option<f64> {
+        let (bid, _) = self.best_bid()?;
+        let (ask, _) = self.best_ask()?;
+        Some(ask - bid)
+    }
+}
+
+fn parse_level((price, size): &(String, String)) -> Option<(f64, f64)> {
+    Some((price.parse().ok()?, size.parse().ok()?))
+}
+
+/// One order book level with exact price and size
+#[cfg(feature = "decimal")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Level {
+    pub price: rust_decimal::Decimal,
+    pub size: rust_decimal::Decimal,
+}
+
+/// Exact views of the string-encoded levels; requires the `decimal` feature
+#[cfg(feature = "decimal")]
+impl OrderBook {
+    /// Bids and asks in book order, failing on the first unparseable value
+    pub fn levels(&self) -> crate::error::Result<(Vec<Level>, Vec<Level>)> {
+        let parse = |side: &[(String, String)], name: &str| {
+            side.iter()
+                .map(|level| decimal_level(level, name))
+                .collect::<crate::error::Result<Vec<_>>>()
+        };
+        Ok((parse(&self.b, "bid")?, parse(&self.a, "ask")?))
+    }
+
+    /// Highest bid, or `None` if the side is empty or unparseable
+    pub fn best_bid_level(&self) -> Option<Level> {
+        self.b.first().and_then(|l| decimal_level(l, "bid").ok())
+    }
+
+    /// Lowest ask, or `None` if the side is empty or unparseable
+    pub fn best_ask_level(&self) -> Option<Level> {
+        self.a.first().and_then(|l| decimal_level(l, "ask").ok())
+    }
+}
+
+#[cfg(feature = "decimal")]
+fn decimal_level((price, size): &(String, String), side: &str) -> crate::error::Result<Level> {
+    Ok(Level {
+        price: parse_number(&format!("{}_price", side), price)?,
+        size: parse_number(&format!("{}_size", side), size)?,
+    })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InstrumentInfo {
     pub symbol: String,
@@ -74,6 +321,40 @@ pub struct InstrumentInfo {
     pub settle_coin: String,
     #[serde(rename = "priceScale")]
     pub price_scale: String,
+    #[serde(rename = "lotSizeFilter", default)]
+    pub lot_size_filter: Option<LotSizeFilter>,
+    #[serde(rename = "priceFilter", default)]
+    pub price_filter: Option<PriceFilter>,
+}
+
+/// Order quantity limits of an instrument
+///
+/// Spot instruments report `base_precision` instead of `qty_step` and have no
+/// `min_notional_value`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LotSizeFilter {
+    pub min_order_qty: String,
+    pub max_order_qty: String,
+    #[serde(default)]
+    pub qty_step: Option<String>,
+    #[serde(default)]
+    pub min_notional_value: Option<String>,
+    #[serde(default)]
+    pub base_precision: Option<String>,
+}
+
+/// Order price limits of an instrument
+///
+/// Spot instruments only report `tick_size`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceFilter {
+    #[serde(default)]
+    pub min_price: Option<String>,
+    #[serde(default)]
+    pub max_price: Option<String>,
+    pub tick_size: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,10 +374,132 @@ pub struct Ticker {
     pub ask1_price: String,
     #[serde(rename = "ask1Size")]
     pub ask1_size: String,
+    #[serde(rename = "volume24h", default)]
+    pub volume_24h: String,
+    #[serde(rename = "turnover24h", default)]
+    pub turnover_24h: String,
+    #[serde(rename = "highPrice24h", default)]
+    pub high_price_24h: String,
+    #[serde(rename = "lowPrice24h", default)]
+    pub low_price_24h: String,
+    /// Relative change over 24 hours, e.g. `"0.012"` for +1.2%; not sent for options
+    #[serde(rename = "price24hPcnt", default)]
+    pub price_24h_pcnt: Option<String>,
+    /// Not sent for options
+    #[serde(rename = "prevPrice24h", default)]
+    pub prev_price_24h: Option<String>,
+    /// Not sent for spot
+    #[serde(rename = "openInterest", default)]
+    pub open_interest: Option<String>,
+    /// Greeks and implied volatility; only sent for options
+    #[serde(flatten)]
+    pub greeks: Option<OptionGreeks>,
+}
+
+/// Generates a numeric accessor per string field, e.g. `last_price_f64()`
+macro_rules! numeric_accessors {
+    ($($field:ident => $f64_name:ident, $decimal_name:ident;)*) => {
+        $(
+            pub fn $f64_name(&self) -> crate::error::Result<f64> {
+                parse_number(stringify!($field), &self.$field)
+            }
+
+            #[cfg(feature = "decimal")]
+            pub fn $decimal_name(&self) -> crate::error::Result<rust_decimal::Decimal> {
+                parse_number(stringify!($field), &self.$field)
+            }
+        )*
+    };
+}
+
+/// Numeric views of the string-encoded prices and sizes
+///
+/// The `_decimal` variants require the `decimal` feature.
+impl Ticker {
+    numeric_accessors! {
+        last_price => last_price_f64, last_price_decimal;
+        index_price => index_price_f64, index_price_decimal;
+        mark_price => mark_price_f64, mark_price_decimal;
+        bid1_price => bid1_price_f64, bid1_price_decimal;
+        bid1_size => bid1_size_f64, bid1_size_decimal;
+        ask1_price => ask1_price_f64, ask1_price_decimal;
+        ask1_size => ask1_size_f64, ask1_size_decimal;
+        volume_24h => volume_24h_f64, volume_24h_decimal;
+        turnover_24h => turnover_24h_f64, turnover_24h_decimal;
+        high_price_24h => high_price_24h_f64, high_price_24h_decimal;
+        low_price_24h => low_price_24h_f64, low_price_24h_decimal;
+    }
+}
+
+/// Top-of-book snapshot figures; requires the `decimal` feature
+///
+/// Each returns `None` when `bid1_price` or `ask1_price` is empty or
+/// unparseable.
+#[cfg(feature = "decimal")]
+impl Ticker {
+    /// Midpoint of the best bid and ask
+    pub fn mid_price(&self) -> Option<rust_decimal::Decimal> {
+        let (bid, ask) = self.top_of_book()?;
+        (bid + ask).checked_div(rust_decimal::Decimal::TWO)
+    }
+
+    /// Best ask minus best bid
+    pub fn spread(&self) -> Option<rust_decimal::Decimal> {
+        let (bid, ask) = self.top_of_book()?;
+        ask.checked_sub(bid)
+    }
+
+    /// Spread relative to the mid price, in basis points
+    pub fn spread_bps(&self) -> Option<rust_decimal::Decimal> {
+        let bps = self
+            .spread()?
+            .checked_mul(rust_decimal::Decimal::from(10_000))?;
+        bps.checked_div(self.mid_price()?)
+    }
+
+    fn top_of_book(&self) -> Option<(rust_decimal::Decimal, rust_decimal::Decimal)> {
+        Some((self.bid1_price.parse().ok()?, self.ask1_price.parse().ok()?))
+    }
+}
+
+/// Option-only ticker fields
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionGreeks {
+    pub delta: String,
+    pub gamma: String,
+    pub vega: String,
+    pub theta: String,
+    /// Implied volatility at the mark price
+    pub mark_iv: String,
+    pub bid1_iv: String,
+    pub ask1_iv: String,
+    pub underlying_price: String,
+}
+
+impl OptionGreeks {
+    numeric_accessors! {
+        delta => delta_f64, delta_decimal;
+        gamma => gamma_f64, gamma_decimal;
+        vega => vega_f64, vega_decimal;
+        theta => theta_f64, theta_decimal;
+        mark_iv => mark_iv_f64, mark_iv_decimal;
+        bid1_iv => bid1_iv_f64, bid1_iv_decimal;
+        ask1_iv => ask1_iv_f64, ask1_iv_decimal;
+        underlying_price => underlying_price_f64, underlying_price_decimal;
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(field: &str, value: &str) -> crate::error::Result<T> {
+    value.parse().map_err(|_| BybitError::ParseError {
+        field: field.to_string(),
+        value: value.to_string(),
+    })
 }
 
 /// Wrapper for ticker list response
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TickerList {
     pub list: Vec<Ticker>,
     pub next_page_cursor: Option<String>,
@@ -104,11 +507,281 @@ pub struct TickerList {
 
 /// Wrapper for instrument list response
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct InstrumentList {
     pub list: Vec<InstrumentInfo>,
     pub next_page_cursor: Option<String>,
 }
 
+/// Wrapper for recent public trades response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentTradeList {
+    pub category: String,
+    pub list: Vec<RecentTrade>,
+}
+
+/// Public trade from the recent trades endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentTrade {
+    pub exec_id: String,
+    pub symbol: String,
+    pub price: String,
+    pub size: String,
+    pub side: String,
+    pub time: String,
+    #[serde(default)]
+    pub is_block_trade: bool,
+}
+
+/// Wrapper for delivery price response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryPriceList {
+    pub category: String,
+    pub list: Vec<DeliveryPrice>,
+    #[serde(default)]
+    pub next_page_cursor: Option<String>,
+}
+
+/// Settlement price of an expired option or futures contract
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryPrice {
+    pub symbol: String,
+    pub delivery_price: String,
+    /// Milliseconds since the epoch
+    pub delivery_time: String,
+}
+
+impl DeliveryPrice {
+    numeric_accessors! {
+        delivery_price => delivery_price_f64, delivery_price_decimal;
+    }
+}
+
+/// Insurance fund balances from `/v5/market/insurance`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InsuranceList {
+    /// Milliseconds since the epoch
+    pub updated_time: String,
+    pub list: Vec<Insurance>,
+}
+
+/// Balance of one insurance fund pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Insurance {
+    pub coin: String,
+    /// Symbols sharing this pool, comma separated; empty for the shared pool
+    #[serde(default)]
+    pub symbols: String,
+    pub balance: String,
+    /// Balance in USD
+    pub value: String,
+}
+
+impl Insurance {
+    numeric_accessors! {
+        balance => balance_f64, balance_decimal;
+        value => value_f64, value_decimal;
+    }
+}
+
+/// Wrapper for risk limit response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RiskLimitList {
+    pub category: String,
+    pub list: Vec<RiskLimit>,
+    #[serde(default)]
+    pub next_page_cursor: Option<String>,
+}
+
+/// One tier of a symbol's risk limit table
+///
+/// Larger positions move into higher tiers with stricter margin rates and a
+/// lower maximum leverage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RiskLimit {
+    pub id: u64,
+    pub symbol: String,
+    pub risk_limit_value: String,
+    pub maintenance_margin: String,
+    pub initial_margin: String,
+    pub max_leverage: String,
+    #[serde(default)]
+    pub is_lowest_risk: u8,
+}
+
+impl RiskLimit {
+    numeric_accessors! {
+        risk_limit_value => risk_limit_value_f64, risk_limit_value_decimal;
+        maintenance_margin => maintenance_margin_f64, maintenance_margin_decimal;
+        initial_margin => initial_margin_f64, initial_margin_decimal;
+        max_leverage => max_leverage_f64, max_leverage_decimal;
+    }
+}
+
+impl RiskLimitList {
+    /// Lowest tier of `symbol` whose `risk_limit_value` covers a position
+    /// worth `position_value`, or `None` if the position exceeds every tier
+    ///
+    /// Its `max_leverage` caps the leverage available at that size.
+    pub fn tier_for(&self, symbol: &str, position_value: f64) -> Option<&RiskLimit> {
+        self.list
+            .iter()
+            .filter(|tier| tier.symbol == symbol)
+            .filter_map(|tier| Some((tier.risk_limit_value_f64().ok()?, tier)))
+            .filter(|(limit, _)| *limit >= position_value)
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, tier)| tier)
+    }
+}
+
+/// Result of moving a position to another risk limit tier
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RiskLimitUpdate {
+    pub category: String,
+    pub risk_id: u64,
+    pub risk_limit_value: String,
+}
+
+impl RiskLimitUpdate {
+    numeric_accessors! {
+        risk_limit_value => risk_limit_value_f64, risk_limit_value_decimal;
+    }
+}
+
+/// Candle interval for the kline endpoints
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum Interval {
+    #[serde(rename = "1")]
+    Min1,
+    #[serde(rename = "3")]
+    Min3,
+    #[serde(rename = "5")]
+    Min5,
+    #[serde(rename = "15")]
+    Min15,
+    #[serde(rename = "30")]
+    Min30,
+    #[serde(rename = "60")]
+    Min60,
+    #[serde(rename = "120")]
+    Min120,
+    #[serde(rename = "240")]
+    Min240,
+    #[serde(rename = "360")]
+    Min360,
+    #[serde(rename = "720")]
+    Min720,
+    #[serde(rename = "D")]
+    Day,
+    #[serde(rename = "W")]
+    Week,
+    #[serde(rename = "M")]
+    Month,
+}
+
+impl Interval {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Interval::Min1 => "1",
+            Interval::Min3 => "3",
+            Interval::Min5 => "5",
+            Interval::Min15 => "15",
+            Interval::Min30 => "30",
+            Interval::Min60 => "60",
+            Interval::Min120 => "120",
+            Interval::Min240 => "240",
+            Interval::Min360 => "360",
+            Interval::Min720 => "720",
+            Interval::Day => "D",
+            Interval::Week => "W",
+            Interval::Month => "M",
+        }
+    }
+}
+
+impl From<Interval> for String {
+    fn from(interval: Interval) -> Self {
+        interval.as_str().to_string()
+    }
+}
+
+impl AsRef<str> for Interval {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Single candle from the kline endpoints
+///
+/// Bybit returns each candle as a positional array of strings:
+/// `[startTime, open, high, low, close, volume, turnover]`. Mark and index
+/// price candles stop after `close`, leaving `volume` and `turnover` empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kline {
+    pub start_time: i64,
+    pub open: String,
+    pub high: String,
+    pub low: String,
+    pub close: String,
+    pub volume: String,
+    pub turnover: String,
+}
+
+impl<'de> Deserialize<'de> for Kline {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = Vec::<String>::deserialize(deserializer)?;
+        if fields.len() < 5 {
+            return Err(D::Error::invalid_length(
+                fields.len(),
+                &"an array of 5 to 7 kline fields",
+            ));
+        }
+
+        let mut fields = fields.into_iter();
+        let mut next = || fields.next().unwrap_or_default();
+        let start_time = next().parse().map_err(D::Error::custom)?;
+
+        Ok(Kline {
+            start_time,
+            open: next(),
+            high: next(),
+            low: next(),
+            close: next(),
+            volume: next(),
+            turnover: next(),
+        })
+    }
+}
+
+impl Serialize for Kline {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(7))?;
+        seq.serialize_element(&self.start_time.to_string())?;
+        seq.serialize_element(&self.open)?;
+        seq.serialize_element(&self.high)?;
+        seq.serialize_element(&self.low)?;
+        seq.serialize_element(&self.close)?;
+        seq.serialize_element(&self.volume)?;
+        seq.serialize_element(&self.turnover)?;
+        seq.end()
+    }
+}
+
+/// Wrapper for kline list response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KlineList {
+    pub category: String,
+    pub symbol: String,
+    pub list: Vec<Kline>,
+}
+
 /// Wrapper for wallet balance response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WalletBalance {
@@ -140,16 +813,65 @@ pub struct AccountBalance {
     pub coin: Vec<CoinBalance>,
 }
 
+impl AccountBalance {
+    numeric_accessors! {
+        total_equity => total_equity_f64, total_equity_decimal;
+        total_wallet_balance => total_wallet_balance_f64, total_wallet_balance_decimal;
+        total_margin_balance => total_margin_balance_f64, total_margin_balance_decimal;
+        total_available_balance => total_available_balance_f64, total_available_balance_decimal;
+        total_perp_upl => total_perp_upl_f64, total_perp_upl_decimal;
+        total_initial_margin => total_initial_margin_f64, total_initial_margin_decimal;
+        total_maintenance_margin => total_maintenance_margin_f64, total_maintenance_margin_decimal;
+    }
+
+    /// Balance of `coin`, if the account holds it
+    pub fn coin_balance(&self, coin: &str) -> Option<&CoinBalance> {
+        self.coin.iter().find(|c| c.coin == coin)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CoinBalance {
     pub coin: String,
+    #[serde(rename = "walletBalance")]
     pub wallet_balance: String,
-    #[serde(rename = "transferBalance")]
+    /// Deprecated by Bybit and no longer sent for unified accounts
+    #[serde(rename = "transferBalance", default)]
     pub transfer_balance: String,
+    #[serde(default)]
+    pub equity: String,
+    #[serde(rename = "usdValue", default)]
+    pub usd_value: String,
+    /// Deprecated by Bybit for unified accounts, where it is sent empty
+    #[serde(rename = "availableToWithdraw", default)]
+    pub available_to_withdraw: String,
+    #[serde(rename = "unrealisedPnl", default)]
+    pub unrealised_pnl: String,
+    #[serde(rename = "cumRealisedPnl", default)]
+    pub cum_realised_pnl: String,
+    #[serde(rename = "borrowAmount", default)]
+    pub borrow_amount: String,
+    /// Held by open spot orders
+    #[serde(default)]
+    pub locked: String,
+}
+
+impl CoinBalance {
+    numeric_accessors! {
+        wallet_balance => wallet_balance_f64, wallet_balance_decimal;
+        equity => equity_f64, equity_decimal;
+        usd_value => usd_value_f64, usd_value_decimal;
+        available_to_withdraw => available_to_withdraw_f64, available_to_withdraw_decimal;
+        unrealised_pnl => unrealised_pnl_f64, unrealised_pnl_decimal;
+        cum_realised_pnl => cum_realised_pnl_f64, cum_realised_pnl_decimal;
+        borrow_amount => borrow_amount_f64, borrow_amount_decimal;
+        locked => locked_f64, locked_decimal;
+    }
 }
 
 /// Wrapper for position list response
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PositionList {
     pub list: Vec<Position>,
     pub category: String,
@@ -169,6 +891,598 @@ pub struct Position {
     pub position_value: String,
     #[serde(rename = "unrealisedPnl")]
     pub unrealised_pnl: String,
+    pub leverage: Option<String>,
+    /// Average entry price
+    #[serde(rename = "avgPrice")]
+    pub avg_price: Option<String>,
+    /// Empty when the position cannot be liquidated
+    #[serde(rename = "liqPrice")]
+    pub liq_price: Option<String>,
+    #[serde(rename = "markPrice")]
+    pub mark_price: Option<String>,
+    #[serde(rename = "takeProfit")]
+    pub take_profit: Option<String>,
+    #[serde(rename = "stopLoss")]
+    pub stop_loss: Option<String>,
+    #[serde(rename = "trailingStop")]
+    pub trailing_stop: Option<String>,
+    /// Milliseconds since the epoch
+    #[serde(rename = "createdTime")]
+    pub created_time: Option<String>,
+    /// Milliseconds since the epoch
+    #[serde(rename = "updatedTime")]
+    pub updated_time: Option<String>,
+}
+
+/// Position state returned after adding or reducing isolated margin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginUpdate {
+    pub category: String,
+    pub symbol: String,
+    pub position_idx: u64,
+    #[serde(default)]
+    pub risk_id: u64,
+    #[serde(default)]
+    pub risk_limit_value: String,
+    pub size: String,
+    #[serde(default)]
+    pub avg_price: String,
+    #[serde(default)]
+    pub liq_price: String,
+    #[serde(default)]
+    pub bust_price: String,
+    #[serde(default)]
+    pub mark_price: String,
+    #[serde(default)]
+    pub position_value: String,
+    #[serde(default)]
+    pub leverage: String,
+    #[serde(default)]
+    pub auto_add_margin: u8,
+    #[serde(default)]
+    pub position_status: String,
+    #[serde(rename = "positionIM", default)]
+    pub position_im: String,
+    #[serde(rename = "positionMM", default)]
+    pub position_mm: String,
+    #[serde(default)]
+    pub unrealised_pnl: String,
+    #[serde(default)]
+    pub updated_time: String,
+}
+
+impl MarginUpdate {
+    numeric_accessors! {
+        liq_price => liq_price_f64, liq_price_decimal;
+        position_im => position_im_f64, position_im_decimal;
+        position_mm => position_mm_f64, position_mm_decimal;
+    }
+}
+
+/// Wrapper for execution list response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionList {
+    pub category: String,
+    pub list: Vec<Execution>,
+    pub next_page_cursor: Option<String>,
+}
+
+/// Single fill from the execution list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Execution {
+    pub symbol: String,
+    pub order_id: String,
+    #[serde(default)]
+    pub order_link_id: String,
+    pub side: String,
+    #[serde(default)]
+    pub order_price: String,
+    #[serde(default)]
+    pub order_qty: String,
+    pub exec_id: String,
+    pub exec_price: String,
+    pub exec_qty: String,
+    #[serde(default)]
+    pub exec_fee: String,
+    pub exec_time: String,
+    #[serde(default)]
+    pub exec_type: String,
+    #[serde(default)]
+    pub fee_rate: String,
+    #[serde(default)]
+    pub is_maker: bool,
+}
+
+/// Wrapper for closed PnL response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedPnlList {
+    pub category: String,
+    pub list: Vec<ClosedPnl>,
+    pub next_page_cursor: Option<String>,
+}
+
+/// Realised PnL of a closed position
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedPnl {
+    pub symbol: String,
+    pub order_id: String,
+    pub side: String,
+    pub qty: String,
+    pub order_price: String,
+    pub order_type: String,
+    pub exec_type: String,
+    pub closed_size: String,
+    pub cum_entry_value: String,
+    pub avg_entry_price: String,
+    pub cum_exit_value: String,
+    pub avg_exit_price: String,
+    pub closed_pnl: String,
+    pub fill_count: String,
+    pub leverage: String,
+    pub created_time: String,
+    pub updated_time: String,
+}
+
+/// Wrapper for transaction log response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionLogList {
+    pub list: Vec<TransactionLog>,
+    pub next_page_cursor: Option<String>,
+}
+
+/// Balance-changing event from `/v5/account/transaction-log`
+///
+/// `change` is the net effect on the wallet balance and `cash_balance` the
+/// balance after it was applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionLog {
+    pub id: String,
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(default)]
+    pub category: String,
+    /// `Buy`, `Sell` or `None`
+    #[serde(default)]
+    pub side: String,
+    /// e.g. `TRADE`, `SETTLEMENT`, `TRANSFER_IN`, `TRANSFER_OUT`, `DELIVERY`
+    #[serde(rename = "type")]
+    pub transaction_type: String,
+    pub currency: String,
+    #[serde(default)]
+    pub qty: String,
+    #[serde(default)]
+    pub trade_price: String,
+    pub change: String,
+    pub cash_flow: String,
+    pub cash_balance: String,
+    #[serde(default)]
+    pub fee: String,
+    /// Funding fee; empty for anything but settlements
+    #[serde(default)]
+    pub funding: String,
+    #[serde(default)]
+    pub trade_id: String,
+    #[serde(default)]
+    pub order_id: String,
+    /// Milliseconds since the epoch
+    pub transaction_time: String,
+}
+
+impl TransactionLog {
+    numeric_accessors! {
+        change => change_f64, change_decimal;
+        cash_flow => cash_flow_f64, cash_flow_decimal;
+        cash_balance => cash_balance_f64, cash_balance_decimal;
+    }
+}
+
+/// Spot margin trading state of a unified account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotMarginState {
+    /// Not sent by `/v5/spot-margin-trade/switch-mode`
+    #[serde(default)]
+    pub spot_leverage: String,
+    /// `1` when spot margin trading is on, `0` when off
+    pub spot_margin_mode: String,
+}
+
+impl SpotMarginState {
+    /// Whether spot margin trading is on
+    pub fn is_enabled(&self) -> bool {
+        self.spot_margin_mode == "1"
+    }
+}
+
+/// Wrapper for collateral info response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollateralInfoList {
+    pub list: Vec<CollateralInfo>,
+}
+
+/// Borrowing and collateral settings of a coin in a unified account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralInfo {
+    pub currency: String,
+    /// Rate at which the coin's value counts towards the account's collateral
+    pub collateral_ratio: String,
+    /// What can be borrowed right now, capped by the platform's remaining quota
+    pub available_to_borrow: String,
+    pub max_borrowing_amount: String,
+    pub borrow_amount: String,
+    pub hourly_borrow_rate: String,
+    pub borrowable: bool,
+    /// Whether the coin can be used as collateral at all
+    pub margin_collateral: bool,
+    /// Whether the user has enabled the coin as collateral
+    pub collateral_switch: bool,
+}
+
+impl CollateralInfo {
+    numeric_accessors! {
+        collateral_ratio => collateral_ratio_f64, collateral_ratio_decimal;
+        available_to_borrow => available_to_borrow_f64, available_to_borrow_decimal;
+        max_borrowing_amount => max_borrowing_amount_f64, max_borrowing_amount_decimal;
+        borrow_amount => borrow_amount_f64, borrow_amount_decimal;
+        hourly_borrow_rate => hourly_borrow_rate_f64, hourly_borrow_rate_decimal;
+    }
+}
+
+/// Wrapper for borrow history response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowHistoryList {
+    pub list: Vec<BorrowInterest>,
+    pub next_page_cursor: Option<String>,
+}
+
+/// Hourly interest charge on a borrowed coin from `/v5/account/borrow-history`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowInterest {
+    pub currency: String,
+    /// Milliseconds since the epoch
+    pub created_time: i64,
+    pub borrow_cost: String,
+    pub hourly_borrow_rate: String,
+    #[serde(rename = "InterestBearingBorrowSize")]
+    pub interest_bearing_borrow_size: String,
+    #[serde(default)]
+    pub cost_exemption: String,
+    pub borrow_amount: String,
+    #[serde(default)]
+    pub unrealised_loss: String,
+    #[serde(default)]
+    pub free_borrowed_amount: String,
+}
+
+impl BorrowInterest {
+    numeric_accessors! {
+        borrow_cost => borrow_cost_f64, borrow_cost_decimal;
+        hourly_borrow_rate => hourly_borrow_rate_f64, hourly_borrow_rate_decimal;
+        borrow_amount => borrow_amount_f64, borrow_amount_decimal;
+        unrealised_loss => unrealised_loss_f64, unrealised_loss_decimal;
+        free_borrowed_amount => free_borrowed_amount_f64, free_borrowed_amount_decimal;
+    }
+}
+
+/// Account settings from `/v5/account/info`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfo {
+    /// `1` classic, `3`/`4` unified trading account (`4` is UTA 1.0 Pro),
+    /// `5`/`6` UTA 2.0
+    pub unified_margin_status: i32,
+    /// `ISOLATED_MARGIN`, `REGULAR_MARGIN` or `PORTFOLIO_MARGIN`
+    pub margin_mode: String,
+    #[serde(default)]
+    pub is_master_trader: bool,
+    /// Disconnected cancel-all protection: `ON` or `OFF`
+    #[serde(default)]
+    pub dcp_status: String,
+    #[serde(default)]
+    pub updated_time: String,
+}
+
+impl AccountInfo {
+    /// Whether this is a unified trading account, which uses
+    /// `accountType=UNIFIED` for wallet balance queries
+    pub fn is_unified(&self) -> bool {
+        matches!(self.unified_margin_status, 3..=6)
+    }
+}
+
+/// Wrapper for fee rate response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeRateList {
+    pub list: Vec<FeeRate>,
+}
+
+/// Maker/taker fee rates for a symbol, as decimal fractions (`"0.0006"` = 0.06%)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeRate {
+    pub symbol: String,
+    /// Only set for options, which are quoted per base coin
+    #[serde(default)]
+    pub base_coin: String,
+    pub taker_fee_rate: String,
+    pub maker_fee_rate: String,
+}
+
+/// Account type on either side of an internal transfer
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum AccountType {
+    #[serde(rename = "UNIFIED")]
+    Unified,
+    #[serde(rename = "FUND")]
+    Fund,
+    #[serde(rename = "CONTRACT")]
+    Contract,
+    #[serde(rename = "SPOT")]
+    Spot,
+}
+
+impl AccountType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountType::Unified => "UNIFIED",
+            AccountType::Fund => "FUND",
+            AccountType::Contract => "CONTRACT",
+            AccountType::Spot => "SPOT",
+        }
+    }
+}
+
+impl From<AccountType> for String {
+    fn from(account_type: AccountType) -> Self {
+        account_type.as_str().to_string()
+    }
+}
+
+/// Result of an internal transfer between account types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferResult {
+    pub transfer_id: String,
+    /// `SUCCESS`, `PENDING` or `FAILED`
+    #[serde(default)]
+    pub status: String,
+}
+
+/// Wrapper for internal transfer records response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferRecordList {
+    pub list: Vec<TransferRecord>,
+    pub next_page_cursor: Option<String>,
+}
+
+/// Past internal transfer from `/v5/asset/transfer/query-inter-transfer-list`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferRecord {
+    pub transfer_id: String,
+    pub coin: String,
+    pub amount: String,
+    pub from_account_type: String,
+    pub to_account_type: String,
+    /// Milliseconds since the epoch
+    pub timestamp: String,
+    pub status: String,
+}
+
+impl TransferRecord {
+    numeric_accessors! {
+        amount => amount_f64, amount_decimal;
+    }
+}
+
+/// Wrapper for withdrawal records response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecordList {
+    pub rows: Vec<WithdrawRecord>,
+    pub next_page_cursor: Option<String>,
+}
+
+/// Past withdrawal from `/v5/asset/withdraw/query-record`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecord {
+    pub withdraw_id: String,
+    pub coin: String,
+    pub chain: String,
+    pub amount: String,
+    #[serde(rename = "txID")]
+    pub tx_id: String,
+    /// e.g. `SecurityCheck`, `Pending`, `success`, `CancelByUser`, `Reject`, `Fail`
+    pub status: String,
+    pub to_address: String,
+    #[serde(default)]
+    pub tag: String,
+    pub withdraw_fee: String,
+    /// Milliseconds since the epoch
+    pub create_time: String,
+    /// Milliseconds since the epoch
+    pub update_time: String,
+}
+
+impl WithdrawRecord {
+    numeric_accessors! {
+        amount => amount_f64, amount_decimal;
+        withdraw_fee => withdraw_fee_f64, withdraw_fee_decimal;
+    }
+}
+
+/// Wrapper for deposit records response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecordList {
+    pub rows: Vec<DepositRecord>,
+    pub next_page_cursor: Option<String>,
+}
+
+/// Past on-chain deposit from `/v5/asset/deposit/query-record`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    pub coin: String,
+    pub chain: String,
+    pub amount: String,
+    #[serde(rename = "txID")]
+    pub tx_id: String,
+    /// `0` unknown, `1` to be confirmed, `2` processing, `3` success, `4` failed
+    pub status: i32,
+    pub to_address: String,
+    #[serde(default)]
+    pub tag: String,
+    #[serde(default)]
+    pub deposit_fee: String,
+    /// Milliseconds since the epoch
+    pub success_at: String,
+    #[serde(default)]
+    pub confirmations: String,
+}
+
+impl DepositRecord {
+    numeric_accessors! {
+        amount => amount_f64, amount_decimal;
+    }
+}
+
+/// Withdrawable balance of a coin from `/v5/asset/withdraw/withdrawable-amount`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawableAmount {
+    /// Remaining withdrawal limit in USD
+    pub limit_amount_usd: String,
+    /// Keyed by account type, e.g. `SPOT` or `FUND`
+    pub withdrawable_amount: std::collections::HashMap<String, CoinWithdrawable>,
+}
+
+impl WithdrawableAmount {
+    numeric_accessors! {
+        limit_amount_usd => limit_amount_usd_f64, limit_amount_usd_decimal;
+    }
+}
+
+/// Withdrawable balance of a coin in one account type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinWithdrawable {
+    pub coin: String,
+    pub withdrawable_amount: String,
+    pub available_balance: String,
+}
+
+impl CoinWithdrawable {
+    numeric_accessors! {
+        withdrawable_amount => withdrawable_amount_f64, withdrawable_amount_decimal;
+        available_balance => available_balance_f64, available_balance_decimal;
+    }
+}
+
+/// Result of a withdrawal request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawalResult {
+    /// Withdrawal id, matching [`WithdrawRecord::withdraw_id`]
+    pub id: String,
+}
+
+/// Wrapper for coin info response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoinInfoList {
+    pub rows: Vec<CoinInfo>,
+}
+
+/// Deposit and withdrawal settings of a coin from `/v5/asset/coin/query-info`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinInfo {
+    pub name: String,
+    pub coin: String,
+    /// Remaining single-day withdrawal quota
+    pub remain_amount: String,
+    pub chains: Vec<ChainInfo>,
+}
+
+impl CoinInfo {
+    /// Settings for `chain`, if the coin can be moved on it
+    pub fn chain(&self, chain: &str) -> Option<&ChainInfo> {
+        self.chains.iter().find(|c| c.chain == chain)
+    }
+}
+
+/// One network a coin can be deposited or withdrawn on
+///
+/// Pass `chain` (not `chain_type`) to
+/// [`create_withdrawal`](crate::BybitClient::create_withdrawal).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainInfo {
+    pub chain: String,
+    pub chain_type: String,
+    /// Block confirmations needed before a deposit is credited
+    #[serde(rename = "confirmation")]
+    pub confirmations: String,
+    /// Empty when withdrawals are not supported on this chain
+    pub withdraw_fee: String,
+    pub deposit_min: String,
+    #[serde(rename = "withdrawMin")]
+    pub min_withdraw: String,
+    /// Decimal places allowed in deposit and withdrawal amounts
+    pub min_accuracy: String,
+    /// `1` if deposits are open, `0` if suspended
+    pub chain_deposit: String,
+    /// `1` if withdrawals are open, `0` if suspended
+    pub chain_withdraw: String,
+}
+
+impl ChainInfo {
+    numeric_accessors! {
+        withdraw_fee => withdraw_fee_f64, withdraw_fee_decimal;
+        deposit_min => deposit_min_f64, deposit_min_decimal;
+        min_withdraw => min_withdraw_f64, min_withdraw_decimal;
+    }
+}
+
+/// Balance of a single coin in one account type, from
+/// `/v5/asset/transfer/query-account-coin-balance`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinBalanceResult {
+    pub account_type: String,
+    pub balance: SingleCoinBalance,
+}
+
+/// Coin balance as reported by the asset API
+///
+/// Unlike [`CoinBalance`] in [`WalletBalance`], `transfer_balance` is the
+/// amount that can currently be moved out of the account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SingleCoinBalance {
+    pub coin: String,
+    pub wallet_balance: String,
+    pub transfer_balance: String,
+    #[serde(default)]
+    pub bonus: String,
+}
+
+impl SingleCoinBalance {
+    numeric_accessors! {
+        wallet_balance => wallet_balance_f64, wallet_balance_decimal;
+        transfer_balance => transfer_balance_f64, transfer_balance_decimal;
+    }
 }
 
 /// Order side: Buy or Sell
@@ -204,6 +1518,70 @@ pub enum TimeInForce {
     RPI,
 }
 
+impl Side {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Side::Buy => "Buy",
+            Side::Sell => "Sell",
+        }
+    }
+}
+
+impl OrderType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderType::Market => "Market",
+            OrderType::Limit => "Limit",
+        }
+    }
+}
+
+impl TimeInForce {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeInForce::GTC => "GTC",
+            TimeInForce::IOC => "IOC",
+            TimeInForce::FOK => "FOK",
+            TimeInForce::PostOnly => "PostOnly",
+            TimeInForce::RPI => "RPI",
+        }
+    }
+}
+
+impl From<Side> for String {
+    fn from(side: Side) -> Self {
+        side.as_str().to_string()
+    }
+}
+
+impl From<OrderType> for String {
+    fn from(order_type: OrderType) -> Self {
+        order_type.as_str().to_string()
+    }
+}
+
+impl From<TimeInForce> for String {
+    fn from(time_in_force: TimeInForce) -> Self {
+        time_in_force.as_str().to_string()
+    }
+}
+
+macro_rules! impl_try_from_wire {
+    ($($enum:ident),*) => {
+        $(
+            impl TryFrom<&str> for $enum {
+                type Error = BybitError;
+
+                fn try_from(value: &str) -> Result<Self, Self::Error> {
+                    from_wire_name(stringify!($enum), value)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_wire!(Side, OrderType, TimeInForce, OrderStatus, AccountType);
+
 /// Order status
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum OrderStatus {
@@ -217,10 +1595,102 @@ pub enum OrderStatus {
     Cancelled,
     #[serde(rename = "Rejected")]
     Rejected,
+    #[serde(rename = "PartiallyFilledCanceled")]
+    PartiallyFilledCanceled,
+    /// Conditional order waiting for its trigger price
+    #[serde(rename = "Untriggered")]
+    Untriggered,
+    #[serde(rename = "Triggered")]
+    Triggered,
+    #[serde(rename = "Deactivated")]
+    Deactivated,
+}
+
+/// Position mode for `/v5/position/switch-mode`
+///
+/// Bybit sends this as an integer: `0` for one-way, `3` for hedge mode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PositionMode {
+    /// One-way mode: a single position per symbol
+    MergedSingle,
+    /// Hedge mode: separate buy and sell positions
+    BothSides,
+}
+
+impl PositionMode {
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            PositionMode::MergedSingle => 0,
+            PositionMode::BothSides => 3,
+        }
+    }
+}
+
+impl Serialize for PositionMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> Deserialize<'de> for PositionMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match u8::deserialize(deserializer)? {
+            0 => Ok(PositionMode::MergedSingle),
+            3 => Ok(PositionMode::BothSides),
+            other => Err(D::Error::custom(format!("unknown position mode {}", other))),
+        }
+    }
+}
+
+/// Account-wide margin mode for `/v5/account/set-margin-mode`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum MarginMode {
+    #[serde(rename = "ISOLATED_MARGIN")]
+    Isolated,
+    #[serde(rename = "REGULAR_MARGIN")]
+    RegularMargin,
+    #[serde(rename = "PORTFOLIO_MARGIN")]
+    PortfolioMargin,
+}
+
+/// Per-symbol margin mode for `/v5/position/switch-isolated`
+///
+/// Bybit sends this as an integer (`tradeMode`): `0` for cross margin, `1` for
+/// isolated. Not to be confused with the account-wide [`MarginMode`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TradeMode {
+    Cross,
+    Isolated,
+}
+
+impl TradeMode {
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            TradeMode::Cross => 0,
+            TradeMode::Isolated => 1,
+        }
+    }
+}
+
+impl Serialize for TradeMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.as_u8())
+    }
+}
+
+impl<'de> Deserialize<'de> for TradeMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match u8::deserialize(deserializer)? {
+            0 => Ok(TradeMode::Cross),
+            1 => Ok(TradeMode::Isolated),
+            other => Err(D::Error::custom(format!("unknown trade mode {}", other))),
+        }
+    }
 }
 
 /// Wrapper for order list response
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct OrderList {
     pub list: Vec<Order>,
     pub next_page_cursor: Option<String>,
@@ -228,6 +1698,7 @@ pub struct OrderList {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Order {
     pub order_id: String,
     pub order_link_id: String,
@@ -239,6 +1710,7 @@ pub struct Order {
     pub time_in_force: String,
     pub create_type: String,
     pub cancel_type: String,
+    #[serde(rename = "orderStatus")]
     pub status: String,
     pub leaves_qty: String,
     pub cum_exec_qty: String,
@@ -259,6 +1731,26 @@ pub struct Order {
     pub close_on_trigger: Option<bool>,
 }
 
+/// Typed views of the string fields; values the enums don't know are
+/// reported as [`BybitError::InvalidEnumValue`]
+impl Order {
+    pub fn side_enum(&self) -> Result<Side, BybitError> {
+        Side::try_from(self.side.as_str())
+    }
+
+    pub fn order_type_enum(&self) -> Result<OrderType, BybitError> {
+        OrderType::try_from(self.order_type.as_str())
+    }
+
+    pub fn time_in_force_enum(&self) -> Result<TimeInForce, BybitError> {
+        TimeInForce::try_from(self.time_in_force.as_str())
+    }
+
+    pub fn status_enum(&self) -> Result<OrderStatus, BybitError> {
+        OrderStatus::try_from(self.status.as_str())
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CreateOrderRequest {
     pub category: String,
@@ -311,6 +1803,24 @@ impl CreateOrderRequest {
 }
 
 /// Builder for CreateOrderRequest with fluent API
+///
+/// [`build`](Self::build) never panics: a missing `symbol`, `side` or
+/// `order_type` is reported as `MissingRequiredField`, so user input can be
+/// validated without taking down the task.
+///
+/// ```rust
+/// use rusty_bybit::{BybitError, CreateOrderRequest};
+///
+/// let result = CreateOrderRequest::builder()
+///     .symbol("BTCUSDT")
+///     .order_type("Market")
+///     .build();
+///
+/// match result {
+///     Err(BybitError::MissingRequiredField { field_name }) => assert_eq!(field_name, "side"),
+///     other => panic!("unexpected: {:?}", other),
+/// }
+/// ```
 #[derive(Debug, Default)]
 pub struct CreateOrderRequestBuilder {
     category: Option<String>,
@@ -337,6 +1847,10 @@ pub struct CreateOrderRequestBuilder {
     order_filter: Option<String>,
 }
 
+/// `category`, `side`, `order_type` and `time_in_force` take either a wire
+/// string or the matching enum ([`Category`], [`Side`], [`OrderType`],
+/// [`TimeInForce`]); the enums convert to Bybit's exact spelling, so casing
+/// mistakes like `"buy"` are caught at compile time.
 impl CreateOrderRequestBuilder {
     pub fn category(mut self, category: impl Into<String>) -> Self {
         self.category = Some(category.into());
@@ -448,12 +1962,13 @@ impl CreateOrderRequestBuilder {
         self
     }
 
-    pub fn build(self) -> CreateOrderRequest {
-        CreateOrderRequest {
+    /// Fails with `MissingRequiredField` if `symbol`, `side` or `order_type` is unset.
+    pub fn build(self) -> crate::error::Result<CreateOrderRequest> {
+        Ok(CreateOrderRequest {
             category: self.category.unwrap_or_else(|| "linear".to_string()),
-            symbol: self.symbol.expect("symbol is required"),
-            side: self.side.expect("side is required"),
-            order_type: self.order_type.expect("order_type is required"),
+            symbol: required(self.symbol, "symbol")?,
+            side: required(self.side, "side")?,
+            order_type: required(self.order_type, "order_type")?,
             qty: self.qty,
             price: self.price,
             time_in_force: self.time_in_force,
@@ -472,14 +1987,427 @@ impl CreateOrderRequestBuilder {
             slippage_tolerance: self.slippage_tolerance,
             trigger_direction: self.trigger_direction,
             order_filter: self.order_filter,
+        })
+    }
+
+    /// Like [`build`](Self::build), but panics if a required field is missing.
+    pub fn build_unchecked(self) -> CreateOrderRequest {
+        match self.build() {
+            Ok(request) => request,
+            Err(BybitError::MissingRequiredField { field_name }) => {
+                panic!("{} is required", field_name)
+            }
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+fn required<T>(value: Option<T>, field_name: &str) -> crate::error::Result<T> {
+    value.ok_or_else(|| BybitError::MissingRequiredField {
+        field_name: field_name.to_string(),
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrderResponse {
+    pub order_id: String,
+    pub order_link_id: String,
+}
+
+/// Orders removed by `/v5/order/cancel-all`
+///
+/// Spot in a unified account may only report `success` without listing the
+/// cancelled orders, in which case `list` is empty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllResult {
+    #[serde(default)]
+    pub list: Vec<CreateOrderResponse>,
+    /// `1` on success, `0` on failure; empty when not sent
+    #[serde(default)]
+    pub success: String,
+}
+
+impl CancelAllResult {
+    /// False only when Bybit explicitly reported `success: "0"`
+    pub fn is_success(&self) -> bool {
+        self.success != "0"
+    }
+
+    /// Exchange-assigned IDs of the cancelled orders
+    pub fn order_ids(&self) -> impl Iterator<Item = &str> {
+        self.list.iter().map(|order| order.order_id.as_str())
+    }
+}
+
+/// Identifies one order in a batch cancel; build it with
+/// [`CancelOrderSpec::by_order_id`] or [`CancelOrderSpec::by_order_link_id`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrderSpec {
+    pub symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_link_id: Option<String>,
+}
+
+impl CancelOrderSpec {
+    pub fn by_order_id(symbol: impl Into<String>, order_id: impl Into<String>) -> Self {
+        Self {
+            symbol: symbol.into(),
+            order_id: Some(order_id.into()),
+            order_link_id: None,
+        }
+    }
+
+    pub fn by_order_link_id(symbol: impl Into<String>, order_link_id: impl Into<String>) -> Self {
+        Self {
+            symbol: symbol.into(),
+            order_id: None,
+            order_link_id: Some(order_link_id.into()),
+        }
+    }
+}
+
+/// Result of a batch order request
+///
+/// `list` and `ext_info` are both in request order; an item whose `ext_info`
+/// code is non-zero failed and its `list` entry carries empty ids.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchOrderResponse {
+    pub list: Vec<CreateOrderResponse>,
+    #[serde(default)]
+    pub ext_info: Vec<ExtInfoItem>,
+}
+
+impl BatchOrderResponse {
+    /// Indices and errors of the items Bybit rejected
+    pub fn failures(&self) -> impl Iterator<Item = (usize, &ExtInfoItem)> {
+        self.ext_info
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| !item.is_success())
+    }
+}
+
+/// Request body for `/v5/order/amend`
+///
+/// The order is identified by `order_id` or `order_link_id`; only the fields
+/// that are set are modified.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendOrderRequest {
+    pub category: String,
+    pub symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_link_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss: Option<String>,
+}
+
+impl AmendOrderRequest {
+    pub fn builder() -> AmendOrderRequestBuilder {
+        AmendOrderRequestBuilder::default()
+    }
+}
+
+/// Builder for AmendOrderRequest with fluent API
+#[derive(Debug, Default)]
+pub struct AmendOrderRequestBuilder {
+    category: Option<String>,
+    symbol: Option<String>,
+    order_id: Option<String>,
+    order_link_id: Option<String>,
+    qty: Option<String>,
+    price: Option<String>,
+    trigger_price: Option<String>,
+    take_profit: Option<String>,
+    stop_loss: Option<String>,
+}
+
+impl AmendOrderRequestBuilder {
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn order_id(mut self, order_id: impl Into<String>) -> Self {
+        self.order_id = Some(order_id.into());
+        self
+    }
+
+    pub fn order_link_id(mut self, order_link_id: impl Into<String>) -> Self {
+        self.order_link_id = Some(order_link_id.into());
+        self
+    }
+
+    pub fn qty(mut self, qty: impl Into<String>) -> Self {
+        self.qty = Some(qty.into());
+        self
+    }
+
+    pub fn price(mut self, price: impl Into<String>) -> Self {
+        self.price = Some(price.into());
+        self
+    }
+
+    pub fn trigger_price(mut self, trigger_price: impl Into<String>) -> Self {
+        self.trigger_price = Some(trigger_price.into());
+        self
+    }
+
+    pub fn take_profit(mut self, take_profit: impl Into<String>) -> Self {
+        self.take_profit = Some(take_profit.into());
+        self
+    }
+
+    pub fn stop_loss(mut self, stop_loss: impl Into<String>) -> Self {
+        self.stop_loss = Some(stop_loss.into());
+        self
+    }
+
+    /// Fails with `MissingRequiredField` if `symbol` is unset or if neither
+    /// `order_id` nor `order_link_id` identifies the order.
+    pub fn build(self) -> crate::error::Result<AmendOrderRequest> {
+        let symbol = required(self.symbol, "symbol")?;
+        if self.order_id.is_none() && self.order_link_id.is_none() {
+            return Err(BybitError::MissingRequiredField {
+                field_name: "order_id or order_link_id".to_string(),
+            });
         }
+
+        Ok(AmendOrderRequest {
+            category: self.category.unwrap_or_else(|| "linear".to_string()),
+            symbol,
+            order_id: self.order_id,
+            order_link_id: self.order_link_id,
+            qty: self.qty,
+            price: self.price,
+            trigger_price: self.trigger_price,
+            take_profit: self.take_profit,
+            stop_loss: self.stop_loss,
+        })
+    }
+}
+
+/// Request to set TP/SL or a trailing stop on an open position
+///
+/// Only the fields that are set are sent. In `Partial` TP/SL mode,
+/// `tp_size`/`sl_size` limit how much of the position each order closes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradingStopRequest {
+    pub category: String,
+    pub symbol: String,
+    pub position_idx: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub take_profit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_loss: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_stop: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_trigger_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_trigger_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_size: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_size: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tpsl_mode: Option<String>,
+}
+
+impl TradingStopRequest {
+    pub fn builder() -> TradingStopRequestBuilder {
+        TradingStopRequestBuilder::default()
+    }
+}
+
+/// Builder for TradingStopRequest with fluent API
+#[derive(Debug, Default)]
+pub struct TradingStopRequestBuilder {
+    category: Option<String>,
+    symbol: Option<String>,
+    position_idx: Option<u64>,
+    take_profit: Option<String>,
+    stop_loss: Option<String>,
+    trailing_stop: Option<String>,
+    tp_trigger_by: Option<String>,
+    sl_trigger_by: Option<String>,
+    tp_size: Option<String>,
+    sl_size: Option<String>,
+    tpsl_mode: Option<String>,
+}
+
+impl TradingStopRequestBuilder {
+    pub fn category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    /// `0` for one-way mode, `1`/`2` for the buy/sell side in hedge mode
+    pub fn position_idx(mut self, position_idx: u64) -> Self {
+        self.position_idx = Some(position_idx);
+        self
+    }
+
+    pub fn take_profit(mut self, take_profit: impl Into<String>) -> Self {
+        self.take_profit = Some(take_profit.into());
+        self
+    }
+
+    pub fn stop_loss(mut self, stop_loss: impl Into<String>) -> Self {
+        self.stop_loss = Some(stop_loss.into());
+        self
+    }
+
+    pub fn trailing_stop(mut self, trailing_stop: impl Into<String>) -> Self {
+        self.trailing_stop = Some(trailing_stop.into());
+        self
+    }
+
+    pub fn tp_trigger_by(mut self, tp_trigger_by: impl Into<String>) -> Self {
+        self.tp_trigger_by = Some(tp_trigger_by.into());
+        self
+    }
+
+    pub fn sl_trigger_by(mut self, sl_trigger_by: impl Into<String>) -> Self {
+        self.sl_trigger_by = Some(sl_trigger_by.into());
+        self
+    }
+
+    pub fn tp_size(mut self, tp_size: impl Into<String>) -> Self {
+        self.tp_size = Some(tp_size.into());
+        self
+    }
+
+    pub fn sl_size(mut self, sl_size: impl Into<String>) -> Self {
+        self.sl_size = Some(sl_size.into());
+        self
+    }
+
+    /// `Full` or `Partial`
+    pub fn tpsl_mode(mut self, tpsl_mode: impl Into<String>) -> Self {
+        self.tpsl_mode = Some(tpsl_mode.into());
+        self
+    }
+
+    /// Fails with `MissingRequiredField` if `symbol` is unset. `category`
+    /// defaults to `linear` and `position_idx` to one-way mode.
+    pub fn build(self) -> crate::error::Result<TradingStopRequest> {
+        Ok(TradingStopRequest {
+            category: self.category.unwrap_or_else(|| "linear".to_string()),
+            symbol: required(self.symbol, "symbol")?,
+            position_idx: self.position_idx.unwrap_or(0),
+            take_profit: self.take_profit,
+            stop_loss: self.stop_loss,
+            trailing_stop: self.trailing_stop,
+            tp_trigger_by: self.tp_trigger_by,
+            sl_trigger_by: self.sl_trigger_by,
+            tp_size: self.tp_size,
+            sl_size: self.sl_size,
+            tpsl_mode: self.tpsl_mode,
+        })
     }
 }
 
+/// Whether a WebSocket push carries a full snapshot or an incremental delta
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub enum UpdateKind {
+    #[default]
+    #[serde(rename = "snapshot")]
+    Snapshot,
+    #[serde(rename = "delta")]
+    Delta,
+}
+
+/// Orderbook push from the public `orderbook.{depth}.{symbol}` topic
+///
+/// A `Snapshot` replaces the local book entirely. For a `Delta`, a size of
+/// `"0"` means the price level must be removed.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CreateOrderResponse {
-    pub order_id: String,
-    pub order_link_id: String,
+pub struct OrderBookUpdate {
+    pub symbol: String,
+    pub kind: UpdateKind,
+    pub bids: Vec<(String, String)>,
+    pub asks: Vec<(String, String)>,
+    pub update_id: i64,
+    pub seq: i64,
+    pub ts: i64,
+}
+
+/// Ticker push from the public `tickers.{symbol}` topic
+///
+/// Derivatives streams send a `Snapshot` followed by `Delta`s that only carry
+/// the fields that changed, so every field except `symbol` is optional. Spot
+/// streams always send snapshots and omit the derivatives-only fields.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TickerUpdate {
+    pub symbol: String,
+    #[serde(skip_deserializing)]
+    pub kind: UpdateKind,
+    pub last_price: Option<String>,
+    pub index_price: Option<String>,
+    pub mark_price: Option<String>,
+    pub bid1_price: Option<String>,
+    pub bid1_size: Option<String>,
+    pub ask1_price: Option<String>,
+    pub ask1_size: Option<String>,
+    pub price_24h_pcnt: Option<String>,
+    pub high_price_24h: Option<String>,
+    pub low_price_24h: Option<String>,
+    pub volume_24h: Option<String>,
+    pub turnover_24h: Option<String>,
+    pub open_interest: Option<String>,
+    pub funding_rate: Option<String>,
+    pub next_funding_time: Option<String>,
+    #[serde(skip_deserializing)]
+    pub ts: i64,
+}
+
+/// Trade from the public `publicTrade.{symbol}` topic
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicTrade {
+    #[serde(rename = "T")]
+    pub timestamp: i64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "S")]
+    pub side: String,
+    #[serde(rename = "v")]
+    pub size: String,
+    #[serde(rename = "p")]
+    pub price: String,
+    #[serde(rename = "L", default)]
+    pub tick_direction: Option<String>,
+    #[serde(rename = "i")]
+    pub trade_id: String,
+    #[serde(rename = "BT", default)]
+    pub block_trade: bool,
 }
 
 #[cfg(test)]
@@ -510,6 +2438,44 @@ mod tests {
         assert_eq!(spot, Category::Spot);
     }
 
+    #[test]
+    fn test_category_try_from_str() {
+        assert_eq!(Category::try_from("linear").unwrap(), Category::Linear);
+        assert_eq!(Category::try_from("option").unwrap(), Category::Option);
+
+        let error = Category::try_from("linaer").unwrap_err();
+        assert!(matches!(
+            error,
+            BybitError::InvalidEnumValue { ref enum_name, ref value }
+                if enum_name == "Category" && value == "linaer"
+        ));
+    }
+
+    #[test]
+    fn test_interval_wire_strings() {
+        let expected = [
+            (Interval::Min1, "1"),
+            (Interval::Min3, "3"),
+            (Interval::Min5, "5"),
+            (Interval::Min15, "15"),
+            (Interval::Min30, "30"),
+            (Interval::Min60, "60"),
+            (Interval::Min120, "120"),
+            (Interval::Min240, "240"),
+            (Interval::Min360, "360"),
+            (Interval::Min720, "720"),
+            (Interval::Day, "D"),
+            (Interval::Week, "W"),
+            (Interval::Month, "M"),
+        ];
+        for (interval, wire) in expected {
+            assert_eq!(interval.as_str(), wire);
+            assert_eq!(String::from(interval), wire);
+            let parsed: Interval = serde_json::from_str(&format!("\"{}\"", wire)).unwrap();
+            assert_eq!(parsed, interval);
+        }
+    }
+
     #[test]
     fn test_side_serialization() {
         let buy_json = serde_json::to_string(&Side::Buy).unwrap();
@@ -570,6 +2536,67 @@ mod tests {
         assert_eq!(cancelled_json, r#""Cancelled""#);
     }
 
+    #[test]
+    fn test_order_enum_accessors() {
+        let json = r#"{
+            "orderId": "fd4300ae-7847-404e-b947-b46980a4d140",
+            "orderLinkId": "test-000005",
+            "symbol": "ETHUSDT",
+            "side": "Buy",
+            "orderType": "Limit",
+            "price": "1600.00",
+            "qty": "0.10",
+            "timeInForce": "PostOnly",
+            "createType": "CreateByUser",
+            "cancelType": "UNKNOWN",
+            "orderStatus": "Untriggered",
+            "leavesQty": "0.10",
+            "cumExecQty": "0.00",
+            "avgPrice": "0",
+            "createdTime": "1684738540559",
+            "updatedTime": "1684738540561",
+            "positionIdx": 1
+        }"#;
+        let order: Order = serde_json::from_str(json).unwrap();
+
+        assert_eq!(order.side_enum().unwrap(), Side::Buy);
+        assert_eq!(order.order_type_enum().unwrap(), OrderType::Limit);
+        assert_eq!(order.time_in_force_enum().unwrap(), TimeInForce::PostOnly);
+        assert_eq!(order.status_enum().unwrap(), OrderStatus::Untriggered);
+
+        let order = Order {
+            side: "buy".to_string(),
+            ..order
+        };
+        assert!(matches!(
+            order.side_enum(),
+            Err(BybitError::InvalidEnumValue { ref enum_name, ref value })
+                if enum_name == "Side" && value == "buy"
+        ));
+    }
+
+    #[test]
+    fn test_empty_result_deserialization() {
+        assert_eq!(
+            serde_json::from_str::<EmptyResult>("{}").unwrap(),
+            EmptyResult
+        );
+        assert_eq!(
+            serde_json::from_str::<EmptyResult>(r#"{"reasons":[]}"#).unwrap(),
+            EmptyResult
+        );
+        assert_eq!(
+            serde_json::from_str::<EmptyResult>(r#"{"list":[]}"#).unwrap(),
+            EmptyResult
+        );
+        assert_eq!(
+            serde_json::from_str::<EmptyResult>("null").unwrap(),
+            EmptyResult
+        );
+        assert!(serde_json::from_str::<EmptyResult>("[]").is_err());
+        assert_eq!(serde_json::to_string(&EmptyResult).unwrap(), "{}");
+    }
+
     #[test]
     fn test_server_time_serialization() {
         let time = ServerTime {
@@ -590,6 +2617,110 @@ mod tests {
         assert_eq!(time.time_nano, "1234567890123456789");
     }
 
+    fn api_response(ret_ext_info: serde_json::Value) -> ApiResponse<serde_json::Value> {
+        ApiResponse {
+            ret_code: 0,
+            ret_msg: "OK".to_string(),
+            result: serde_json::Value::Null,
+            ret_ext_info,
+            time: 1,
+        }
+    }
+
+    #[test]
+    fn test_ext_info_list_empty() {
+        assert!(
+            api_response(serde_json::json!({}))
+                .ext_info_list()
+                .is_empty()
+        );
+        assert!(
+            api_response(serde_json::Value::Null)
+                .ext_info_list()
+                .is_empty()
+        );
+        assert_eq!(
+            api_response(serde_json::json!({})).ext_info(),
+            RetExtInfo::default()
+        );
+        assert!(RetExtInfo::default().all_succeeded());
+    }
+
+    #[test]
+    fn test_ext_info_list_populated() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {},
+            "retExtInfo": {
+                "list": [
+                    {"code": 0, "msg": "OK"},
+                    {"code": 110001, "msg": "Order does not exist"}
+                ]
+            },
+            "time": 1713434299047
+        }"#;
+        let response: ApiResponse<serde_json::Value> = serde_json::from_str(json).unwrap();
+
+        let ext_info = response.ext_info();
+        assert!(!ext_info.all_succeeded());
+        assert_eq!(ext_info.list, response.ext_info_list());
+
+        let items = response.ext_info_list();
+        assert_eq!(items.len(), 2);
+        assert!(items[0].is_success());
+        assert_eq!(items[1].code, 110001);
+        assert_eq!(items[1].msg, "Order does not exist");
+        assert!(!items[1].is_success());
+    }
+
+    #[test]
+    fn test_ext_info_list_malformed() {
+        let not_a_list = api_response(serde_json::json!({ "list": "oops" }));
+        assert!(not_a_list.ext_info_list().is_empty());
+
+        let bad_items = api_response(serde_json::json!({ "list": [{ "code": "x" }] }));
+        assert!(bad_items.ext_info_list().is_empty());
+    }
+
+    #[test]
+    fn test_cancel_order_spec_serialization() {
+        let by_id = serde_json::to_value(CancelOrderSpec::by_order_id("BTCUSDT", "123")).unwrap();
+        assert_eq!(
+            by_id,
+            serde_json::json!({ "symbol": "BTCUSDT", "orderId": "123" })
+        );
+
+        let by_link_id =
+            serde_json::to_value(CancelOrderSpec::by_order_link_id("ETHUSDT", "grid-1")).unwrap();
+        assert_eq!(
+            by_link_id,
+            serde_json::json!({ "symbol": "ETHUSDT", "orderLinkId": "grid-1" })
+        );
+    }
+
+    #[test]
+    fn test_batch_order_response_failures() {
+        let response = BatchOrderResponse {
+            list: vec![],
+            ext_info: vec![
+                ExtInfoItem {
+                    code: 0,
+                    msg: "OK".to_string(),
+                },
+                ExtInfoItem {
+                    code: 170213,
+                    msg: "Order does not exist.".to_string(),
+                },
+            ],
+        };
+
+        let failures: Vec<_> = response.failures().collect();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, 1);
+        assert_eq!(failures[0].1.code, 170213);
+    }
+
     #[test]
     fn test_ticker_list_serialization() {
         let ticker_list = TickerList {
@@ -601,6 +2732,436 @@ mod tests {
         assert!(json.contains("\"list\":[]"));
     }
 
+    #[test]
+    fn test_kline_list_deserialization() {
+        let json = r#"{
+            "symbol": "BTCUSD",
+            "category": "inverse",
+            "list": [
+                ["1670608800000", "17071", "17073", "17027", "17055.5", "268611", "15.74462667"],
+                ["1670605200000", "17071.5", "17071.5", "17061", "17071", "4177", "0.24469757"]
+            ]
+        }"#;
+
+        let klines: KlineList = serde_json::from_str(json).unwrap();
+        assert_eq!(klines.category, "inverse");
+        assert_eq!(klines.symbol, "BTCUSD");
+        assert_eq!(klines.list.len(), 2);
+
+        let kline = &klines.list[0];
+        assert_eq!(kline.start_time, 1670608800000);
+        assert_eq!(kline.open, "17071");
+        assert_eq!(kline.high, "17073");
+        assert_eq!(kline.low, "17027");
+        assert_eq!(kline.close, "17055.5");
+        assert_eq!(kline.volume, "268611");
+        assert_eq!(kline.turnover, "15.74462667");
+    }
+
+    #[test]
+    fn test_kline_without_turnover() {
+        let json = r#"["1670608800000", "17071", "17073", "17027", "17055.5", "268611"]"#;
+        let kline: Kline = serde_json::from_str(json).unwrap();
+        assert_eq!(kline.volume, "268611");
+        assert!(kline.turnover.is_empty());
+    }
+
+    #[test]
+    fn test_price_kline_list_deserialization() {
+        let json = r#"{
+            "symbol": "BTCUSDT",
+            "category": "linear",
+            "list": [
+                ["1670608800000", "17164.16", "17164.16", "17121.5", "17131.64"]
+            ]
+        }"#;
+
+        let klines: KlineList = serde_json::from_str(json).unwrap();
+        let kline = &klines.list[0];
+        assert_eq!(kline.start_time, 1670608800000);
+        assert_eq!(kline.open, "17164.16");
+        assert_eq!(kline.low, "17121.5");
+        assert_eq!(kline.close, "17131.64");
+        assert!(kline.volume.is_empty());
+        assert!(kline.turnover.is_empty());
+    }
+
+    #[test]
+    fn test_kline_too_short_is_rejected() {
+        let json = r#"["1670608800000", "17071", "17073"]"#;
+        assert!(serde_json::from_str::<Kline>(json).is_err());
+    }
+
+    #[test]
+    fn test_kline_serialization_round_trip() {
+        let json = r#"["1670608800000","17071","17073","17027","17055.5","268611","15.74462667"]"#;
+        let kline: Kline = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&kline).unwrap(), json);
+    }
+
+    #[test]
+    fn test_wallet_balance_accessors() {
+        let json = r#"{
+            "list": [{
+                "totalEquity": "3.31216591",
+                "accountIMRate": "0",
+                "totalMarginBalance": "3.00326056",
+                "totalInitialMargin": "0",
+                "accountType": "UNIFIED",
+                "totalAvailableBalance": "3.00326056",
+                "accountMMRate": "0",
+                "totalPerpUPL": "0",
+                "totalWalletBalance": "3.00326056",
+                "accountLTV": "0",
+                "totalMaintenanceMargin": "0",
+                "coin": [{
+                    "availableToBorrow": "3",
+                    "bonus": "0",
+                    "accruedInterest": "0",
+                    "availableToWithdraw": "0",
+                    "totalOrderIM": "0",
+                    "equity": "0",
+                    "totalPositionMM": "0",
+                    "usdValue": "0",
+                    "spotHedgingQty": "0.01592413",
+                    "unrealisedPnl": "0",
+                    "collateralSwitch": true,
+                    "borrowAmount": "0.0",
+                    "totalPositionIM": "0",
+                    "walletBalance": "0",
+                    "cumRealisedPnl": "0",
+                    "locked": "0",
+                    "marginCollateral": true,
+                    "coin": "BTC"
+                }, {
+                    "equity": "3.00326056",
+                    "usdValue": "3.00318752",
+                    "walletBalance": "3.00326056",
+                    "coin": "USDT"
+                }]
+            }]
+        }"#;
+
+        let wallet: WalletBalance = serde_json::from_str(json).unwrap();
+        let account = &wallet.list[0];
+        assert_eq!(account.total_equity_f64().unwrap(), 3.31216591);
+        assert_eq!(account.total_maintenance_margin_f64().unwrap(), 0.0);
+
+        let usdt = account.coin_balance("USDT").unwrap();
+        assert_eq!(usdt.wallet_balance_f64().unwrap(), 3.00326056);
+        assert_eq!(usdt.usd_value_f64().unwrap(), 3.00318752);
+        assert!(usdt.transfer_balance.is_empty());
+        assert!(account.coin_balance("ETH").is_none());
+
+        let total_usd: f64 = account
+            .coin
+            .iter()
+            .map(|c| c.usd_value_f64().unwrap())
+            .sum();
+        assert_eq!(total_usd, 3.00318752);
+    }
+
+    #[test]
+    fn test_coin_balance_margin_fields() {
+        let json = r#"[{
+            "availableToBorrow": "",
+            "bonus": "0",
+            "accruedInterest": "0",
+            "availableToWithdraw": "1500.25",
+            "totalOrderIM": "12.5",
+            "equity": "2012.73402571",
+            "totalPositionMM": "3.2",
+            "usdValue": "2013.38831744",
+            "spotHedgingQty": "0",
+            "unrealisedPnl": "-4.125",
+            "collateralSwitch": true,
+            "borrowAmount": "250.5",
+            "totalPositionIM": "40.1",
+            "walletBalance": "2016.85902571",
+            "cumRealisedPnl": "-181.3",
+            "locked": "100",
+            "marginCollateral": true,
+            "coin": "USDT"
+        }, {
+            "availableToWithdraw": "",
+            "equity": "0.1",
+            "usdValue": "6700",
+            "unrealisedPnl": "0",
+            "cumRealisedPnl": "0",
+            "borrowAmount": "0.0",
+            "walletBalance": "0.1",
+            "locked": "0",
+            "coin": "BTC"
+        }]"#;
+
+        let coins: Vec<CoinBalance> = serde_json::from_str(json).unwrap();
+        let usdt = &coins[0];
+        assert_eq!(usdt.available_to_withdraw_f64().unwrap(), 1500.25);
+        assert_eq!(usdt.unrealised_pnl_f64().unwrap(), -4.125);
+        assert_eq!(usdt.cum_realised_pnl_f64().unwrap(), -181.3);
+        assert_eq!(usdt.borrow_amount_f64().unwrap(), 250.5);
+        assert_eq!(usdt.locked_f64().unwrap(), 100.0);
+
+        let btc = &coins[1];
+        assert!(btc.available_to_withdraw.is_empty());
+        assert_eq!(btc.borrow_amount_f64().unwrap(), 0.0);
+        assert_eq!(btc.locked, "0");
+    }
+
+    #[test]
+    fn test_execution_list_deserialization() {
+        let json = r#"{
+            "nextPageCursor": "132766%3A2%2C132766%3A2",
+            "category": "linear",
+            "list": [{
+                "symbol": "ETHPERP",
+                "orderType": "Market",
+                "underlyingPrice": "",
+                "orderLinkId": "",
+                "side": "Buy",
+                "indexPrice": "",
+                "orderId": "8c065341-7b52-4ca9-ac2c-37e31ac55c94",
+                "stopOrderType": "UNKNOWN",
+                "leavesQty": "0",
+                "execTime": "1672282722429",
+                "feeCurrency": "",
+                "isMaker": false,
+                "execFee": "0.071409",
+                "feeRate": "0.0006",
+                "execId": "e0cbe81d-0f18-5866-9415-cf319b5dab3b",
+                "tradeIv": "",
+                "blockTradeId": "",
+                "markPrice": "1183.54",
+                "execPrice": "1190.15",
+                "markIv": "",
+                "orderQty": "0.1",
+                "orderPrice": "1236.9",
+                "execValue": "119.015",
+                "execType": "Trade",
+                "execQty": "0.1",
+                "closedSize": "",
+                "seq": 4688002127
+            }]
+        }"#;
+
+        let executions: ExecutionList = serde_json::from_str(json).unwrap();
+        assert_eq!(executions.category, "linear");
+        assert_eq!(
+            executions.next_page_cursor.as_deref(),
+            Some("132766%3A2%2C132766%3A2")
+        );
+
+        let execution = &executions.list[0];
+        assert_eq!(execution.symbol, "ETHPERP");
+        assert_eq!(execution.order_id, "8c065341-7b52-4ca9-ac2c-37e31ac55c94");
+        assert_eq!(execution.side, "Buy");
+        assert_eq!(execution.order_price, "1236.9");
+        assert_eq!(execution.order_qty, "0.1");
+        assert_eq!(execution.exec_id, "e0cbe81d-0f18-5866-9415-cf319b5dab3b");
+        assert_eq!(execution.exec_price, "1190.15");
+        assert_eq!(execution.exec_qty, "0.1");
+        assert_eq!(execution.exec_fee, "0.071409");
+        assert_eq!(execution.exec_time, "1672282722429");
+        assert_eq!(execution.exec_type, "Trade");
+        assert_eq!(execution.fee_rate, "0.0006");
+        assert!(!execution.is_maker);
+    }
+
+    #[test]
+    fn test_borrow_history_list_deserialization() {
+        let json = r#"{
+            "nextPageCursor": "2671153%3A1%2C2671153%3A1",
+            "list": [{
+                "borrowAmount": "1.06333265702840778",
+                "costExemption": "0",
+                "freeBorrowedAmount": "0",
+                "createdTime": 1697439900204,
+                "InterestBearingBorrowSize": "1.06333265702840778",
+                "currency": "BTC",
+                "unrealisedLoss": "0",
+                "hourlyBorrowRate": "0.000001216904",
+                "borrowCost": "0.00000129"
+            }]
+        }"#;
+
+        let history: BorrowHistoryList = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            history.next_page_cursor.as_deref(),
+            Some("2671153%3A1%2C2671153%3A1")
+        );
+        let interest = &history.list[0];
+        assert_eq!(interest.currency, "BTC");
+        assert_eq!(interest.created_time, 1697439900204);
+        assert_eq!(interest.interest_bearing_borrow_size, "1.06333265702840778");
+        assert_eq!(interest.cost_exemption, "0");
+        assert_eq!(interest.borrow_cost_f64().unwrap(), 0.00000129);
+        assert_eq!(interest.unrealised_loss_f64().unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_cancel_all_result_linear_deserialization() {
+        let json = r#"{
+            "list": [
+                {"orderId": "1616024329462743808", "orderLinkId": "1616024329462743809"},
+                {"orderId": "1616024287544869632", "orderLinkId": "1616024287544869633"}
+            ],
+            "success": "1"
+        }"#;
+
+        let result: CancelAllResult = serde_json::from_str(json).unwrap();
+        assert!(result.is_success());
+        assert_eq!(result.list.len(), 2);
+        assert_eq!(result.list[1].order_link_id, "1616024287544869633");
+        assert_eq!(
+            result.order_ids().collect::<Vec<_>>(),
+            vec!["1616024329462743808", "1616024287544869632"]
+        );
+
+        let without_flag: CancelAllResult =
+            serde_json::from_str(r#"{"list":[{"orderId":"1","orderLinkId":""}]}"#).unwrap();
+        assert!(without_flag.is_success());
+        assert_eq!(without_flag.success, "");
+    }
+
+    #[test]
+    fn test_cancel_all_result_spot_success_only() {
+        let result: CancelAllResult = serde_json::from_str(r#"{"success":"1"}"#).unwrap();
+        assert!(result.is_success());
+        assert!(result.list.is_empty());
+
+        let failed: CancelAllResult = serde_json::from_str(r#"{"success":"0"}"#).unwrap();
+        assert!(!failed.is_success());
+    }
+
+    #[test]
+    fn test_position_list_deserialization() {
+        let json = r#"{
+            "list": [{
+                "positionIdx": 0,
+                "riskId": 1,
+                "riskLimitValue": "150",
+                "symbol": "BTCUSD",
+                "side": "Sell",
+                "size": "300",
+                "avgPrice": "27464.50441675",
+                "positionValue": "0.01092319",
+                "tradeMode": 0,
+                "positionStatus": "Normal",
+                "autoAddMargin": 1,
+                "adlRankIndicator": 2,
+                "leverage": "10",
+                "positionBalance": "0.00139186",
+                "markPrice": "28224.50",
+                "liqPrice": "",
+                "bustPrice": "999999.00",
+                "positionMM": "0.0000015",
+                "positionIM": "0.00010923",
+                "tpslMode": "Full",
+                "takeProfit": "0.00",
+                "stopLoss": "0.00",
+                "trailingStop": "0.00",
+                "unrealisedPnl": "-0.00029413",
+                "curRealisedPnl": "0.00013123",
+                "cumRealisedPnl": "-0.00096902",
+                "seq": 5723621632,
+                "isReduceOnly": false,
+                "mmrSysUpdateTime": "",
+                "leverageSysUpdatedTime": "",
+                "sessionAvgPrice": "",
+                "createdTime": "1676538056258",
+                "updatedTime": "1697673600012"
+            }],
+            "nextPageCursor": "BTCUSD%3A1657711949945%2CBTCUSD%3A1657711949945",
+            "category": "inverse"
+        }"#;
+
+        let positions: PositionList = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            positions.next_page_cursor.as_deref(),
+            Some("BTCUSD%3A1657711949945%2CBTCUSD%3A1657711949945")
+        );
+        let position = &positions.list[0];
+        assert_eq!(position.symbol, "BTCUSD");
+        assert_eq!(position.position_idx, 0);
+        assert_eq!(position.leverage.as_deref(), Some("10"));
+        assert_eq!(position.avg_price.as_deref(), Some("27464.50441675"));
+        assert_eq!(position.liq_price.as_deref(), Some(""));
+        assert_eq!(position.mark_price.as_deref(), Some("28224.50"));
+        assert_eq!(position.take_profit.as_deref(), Some("0.00"));
+        assert_eq!(position.stop_loss.as_deref(), Some("0.00"));
+        assert_eq!(position.trailing_stop.as_deref(), Some("0.00"));
+        assert_eq!(position.created_time.as_deref(), Some("1676538056258"));
+        assert_eq!(position.updated_time.as_deref(), Some("1697673600012"));
+    }
+
+    #[test]
+    fn test_position_without_optional_fields() {
+        let json = r#"{
+            "symbol": "BTCUSDT",
+            "positionIdx": 1,
+            "positionStatus": "Normal",
+            "side": "Buy",
+            "size": "0.01",
+            "positionValue": "270",
+            "unrealisedPnl": "0"
+        }"#;
+
+        let position: Position = serde_json::from_str(json).unwrap();
+        assert_eq!(position.position_idx, 1);
+        assert!(position.leverage.is_none());
+        assert!(position.updated_time.is_none());
+    }
+
+    #[test]
+    fn test_closed_pnl_list_deserialization() {
+        let json = r#"{
+            "nextPageCursor": "5a373bfe-188d-4913-9c81-d57ab5be8068%3A1672214887231423699",
+            "category": "linear",
+            "list": [{
+                "symbol": "ETHPERP",
+                "orderType": "Market",
+                "leverage": "3",
+                "updatedTime": "1672214887236",
+                "side": "Sell",
+                "orderId": "5a373bfe-188d-4913-9c81-d57ab5be8068",
+                "closedPnl": "-47.4065323",
+                "avgEntryPrice": "1194.97516667",
+                "qty": "3",
+                "cumEntryValue": "3584.9255",
+                "createdTime": "1672214887231",
+                "orderPrice": "1122.95",
+                "closedSize": "3",
+                "avgExitPrice": "1180.59833333",
+                "execType": "Trade",
+                "fillCount": "4",
+                "cumExitValue": "3541.795"
+            }]
+        }"#;
+
+        let closed: ClosedPnlList = serde_json::from_str(json).unwrap();
+        assert_eq!(closed.category, "linear");
+        assert!(closed.next_page_cursor.is_some());
+
+        let pnl = &closed.list[0];
+        assert_eq!(pnl.symbol, "ETHPERP");
+        assert_eq!(pnl.order_id, "5a373bfe-188d-4913-9c81-d57ab5be8068");
+        assert_eq!(pnl.side, "Sell");
+        assert_eq!(pnl.qty, "3");
+        assert_eq!(pnl.order_price, "1122.95");
+        assert_eq!(pnl.order_type, "Market");
+        assert_eq!(pnl.exec_type, "Trade");
+        assert_eq!(pnl.closed_size, "3");
+        assert_eq!(pnl.cum_entry_value, "3584.9255");
+        assert_eq!(pnl.avg_entry_price, "1194.97516667");
+        assert_eq!(pnl.cum_exit_value, "3541.795");
+        assert_eq!(pnl.avg_exit_price, "1180.59833333");
+        assert_eq!(pnl.closed_pnl, "-47.4065323");
+        assert_eq!(pnl.fill_count, "4");
+        assert_eq!(pnl.leverage, "3");
+        assert_eq!(pnl.created_time, "1672214887231");
+        assert_eq!(pnl.updated_time, "1672214887236");
+    }
+
     #[test]
     fn test_create_order_request_default() {
         let request = CreateOrderRequest {
@@ -650,7 +3211,8 @@ mod tests {
             .symbol("BTCUSDT")
             .side("Buy")
             .order_type("Limit")
-            .build();
+            .build()
+            .unwrap();
 
         assert_eq!(request.category, "linear");
         assert_eq!(request.symbol, "BTCUSDT");
@@ -658,34 +3220,52 @@ mod tests {
         assert_eq!(request.order_type, "Limit");
     }
 
+    fn assert_missing_field(result: crate::error::Result<CreateOrderRequest>, expected: &str) {
+        match result {
+            Err(BybitError::MissingRequiredField { field_name }) => {
+                assert_eq!(field_name, expected)
+            }
+            other => panic!("expected missing {}, got {:?}", expected, other),
+        }
+    }
+
     #[test]
-    #[should_panic(expected = "symbol is required")]
     fn test_create_order_request_builder_missing_symbol() {
-        let _ = CreateOrderRequest::builder()
+        let result = CreateOrderRequest::builder()
             .category("linear")
             .side("Buy")
             .order_type("Limit")
             .build();
+        assert_missing_field(result, "symbol");
     }
 
     #[test]
-    #[should_panic(expected = "side is required")]
     fn test_create_order_request_builder_missing_side() {
-        let _ = CreateOrderRequest::builder()
+        let result = CreateOrderRequest::builder()
             .category("linear")
             .symbol("BTCUSDT")
             .order_type("Limit")
             .build();
+        assert_missing_field(result, "side");
     }
 
     #[test]
-    #[should_panic(expected = "order_type is required")]
     fn test_create_order_request_builder_missing_order_type() {
-        let _ = CreateOrderRequest::builder()
+        let result = CreateOrderRequest::builder()
             .category("linear")
             .symbol("BTCUSDT")
             .side("Buy")
             .build();
+        assert_missing_field(result, "order_type");
+    }
+
+    #[test]
+    #[should_panic(expected = "symbol is required")]
+    fn test_create_order_request_builder_unchecked_panics() {
+        let _ = CreateOrderRequest::builder()
+            .side("Buy")
+            .order_type("Limit")
+            .build_unchecked();
     }
 
     #[test]
@@ -704,7 +3284,8 @@ mod tests {
             .stop_loss("27000")
             .reduce_only(false)
             .close_on_trigger(false)
-            .build();
+            .build()
+            .unwrap();
 
         assert_eq!(request.qty, Some("0.001".to_string()));
         assert_eq!(request.price, Some("28000".to_string()));
@@ -723,7 +3304,8 @@ mod tests {
             .symbol("BTCUSDT")
             .side("Buy")
             .order_type("Limit")
-            .build();
+            .build()
+            .unwrap();
 
         assert_eq!(request.category, "linear");
     }
@@ -737,7 +3319,8 @@ mod tests {
             .qty("0.001")
             .price("28000")
             .time_in_force("GTC")
-            .build();
+            .build()
+            .unwrap();
 
         assert_eq!(request.symbol, "BTCUSDT");
         assert_eq!(request.side, "Buy");
@@ -753,7 +3336,8 @@ mod tests {
             .symbol("BTCUSDT")
             .side("Buy")
             .order_type("Market")
-            .build();
+            .build()
+            .unwrap();
 
         let json = serde_json::to_string(&request).unwrap();
         assert!(json.contains("\"symbol\":\"BTCUSDT\""));
@@ -762,4 +3346,614 @@ mod tests {
         assert!(!json.contains("\"price\""));
         assert!(!json.contains("\"qty\""));
     }
+
+    #[test]
+    fn test_create_order_response_deserialization() {
+        let json = r#"{"orderId":"1321003749386327552","orderLinkId":"spot-test-postonly"}"#;
+        let response: CreateOrderResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.order_id, "1321003749386327552");
+        assert_eq!(response.order_link_id, "spot-test-postonly");
+    }
+
+    #[test]
+    fn test_amend_order_request_builder() {
+        let request = AmendOrderRequest::builder()
+            .symbol("ETHPERP")
+            .order_link_id("linear-004")
+            .qty("0.15")
+            .trigger_price("1145")
+            .build()
+            .unwrap();
+
+        assert_eq!(request.category, "linear");
+        assert_eq!(request.order_link_id, Some("linear-004".to_string()));
+
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(json.contains("\"symbol\":\"ETHPERP\""));
+        assert!(json.contains("\"orderLinkId\":\"linear-004\""));
+        assert!(json.contains("\"triggerPrice\":\"1145\""));
+        assert!(!json.contains("\"orderId\""));
+        assert!(!json.contains("\"price\""));
+    }
+
+    #[test]
+    fn test_amend_order_request_builder_requires_order_identifier() {
+        let result = AmendOrderRequest::builder()
+            .symbol("BTCUSDT")
+            .price("28000")
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(BybitError::MissingRequiredField { ref field_name }) if field_name == "order_id or order_link_id"
+        ));
+    }
+
+    #[test]
+    fn test_amend_order_request_builder_requires_symbol() {
+        let result = AmendOrderRequest::builder().order_id("123").build();
+
+        assert!(matches!(
+            result,
+            Err(BybitError::MissingRequiredField { ref field_name }) if field_name == "symbol"
+        ));
+    }
+
+    #[test]
+    fn test_trading_stop_request_omits_unset_fields() {
+        let request = TradingStopRequest::builder()
+            .symbol("XRPUSDT")
+            .take_profit("0.6")
+            .tp_trigger_by("MarkPrice")
+            .tpsl_mode("Full")
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "category": "linear",
+                "symbol": "XRPUSDT",
+                "positionIdx": 0,
+                "takeProfit": "0.6",
+                "tpTriggerBy": "MarkPrice",
+                "tpslMode": "Full",
+            })
+        );
+    }
+
+    #[test]
+    fn test_trading_stop_request_requires_symbol() {
+        let result = TradingStopRequest::builder().stop_loss("0.4").build();
+
+        assert!(matches!(
+            result,
+            Err(BybitError::MissingRequiredField { ref field_name }) if field_name == "symbol"
+        ));
+    }
+
+    #[test]
+    fn test_position_mode_serializes_as_integer() {
+        assert_eq!(
+            serde_json::to_string(&PositionMode::MergedSingle).unwrap(),
+            "0"
+        );
+        assert_eq!(
+            serde_json::to_string(&PositionMode::BothSides).unwrap(),
+            "3"
+        );
+
+        let mode: PositionMode = serde_json::from_str("3").unwrap();
+        assert_eq!(mode, PositionMode::BothSides);
+        assert!(serde_json::from_str::<PositionMode>("1").is_err());
+    }
+
+    #[test]
+    fn test_margin_mode_serialization() {
+        assert_eq!(
+            serde_json::to_string(&MarginMode::Isolated).unwrap(),
+            r#""ISOLATED_MARGIN""#
+        );
+        assert_eq!(
+            serde_json::to_string(&MarginMode::RegularMargin).unwrap(),
+            r#""REGULAR_MARGIN""#
+        );
+        assert_eq!(
+            serde_json::to_string(&MarginMode::PortfolioMargin).unwrap(),
+            r#""PORTFOLIO_MARGIN""#
+        );
+    }
+
+    #[test]
+    fn test_trade_mode_serializes_as_integer() {
+        assert_eq!(serde_json::to_string(&TradeMode::Cross).unwrap(), "0");
+        assert_eq!(serde_json::to_string(&TradeMode::Isolated).unwrap(), "1");
+
+        let mode: TradeMode = serde_json::from_str("0").unwrap();
+        assert_eq!(mode, TradeMode::Cross);
+        assert!(serde_json::from_str::<TradeMode>("2").is_err());
+    }
+
+    #[test]
+    fn test_instrument_info_filters_deserialization() {
+        let json = r#"{
+            "symbol": "BTCUSDT",
+            "contractType": "LinearPerpetual",
+            "status": "Trading",
+            "baseCoin": "BTC",
+            "quoteCoin": "USDT",
+            "launchTime": "1585526400000",
+            "deliveryTime": "0",
+            "deliveryFeeRate": "",
+            "priceScale": "2",
+            "leverageFilter": {
+                "minLeverage": "1",
+                "maxLeverage": "100.00",
+                "leverageStep": "0.01"
+            },
+            "priceFilter": {
+                "minPrice": "0.10",
+                "maxPrice": "1999999.80",
+                "tickSize": "0.10"
+            },
+            "lotSizeFilter": {
+                "maxOrderQty": "1190.000",
+                "minOrderQty": "0.001",
+                "qtyStep": "0.001",
+                "postOnlyMaxOrderQty": "1190.000",
+                "maxMktOrderQty": "500.000",
+                "minNotionalValue": "5"
+            },
+            "unifiedMarginTrade": true,
+            "fundingInterval": 480,
+            "settleCoin": "USDT",
+            "copyTrading": "both",
+            "upperFundingRate": "0.00375",
+            "lowerFundingRate": "-0.00375"
+        }"#;
+
+        let instrument: InstrumentInfo = serde_json::from_str(json).unwrap();
+        let lot_size = instrument.lot_size_filter.unwrap();
+        assert_eq!(lot_size.min_order_qty, "0.001");
+        assert_eq!(lot_size.max_order_qty, "1190.000");
+        assert_eq!(lot_size.qty_step.as_deref(), Some("0.001"));
+        assert_eq!(lot_size.min_notional_value.as_deref(), Some("5"));
+        assert!(lot_size.base_precision.is_none());
+
+        let price = instrument.price_filter.unwrap();
+        assert_eq!(price.min_price.as_deref(), Some("0.10"));
+        assert_eq!(price.max_price.as_deref(), Some("1999999.80"));
+        assert_eq!(price.tick_size, "0.10");
+    }
+
+    fn ticker() -> Ticker {
+        Ticker {
+            symbol: "BTCUSDT".to_string(),
+            last_price: "16597.00".to_string(),
+            index_price: "16598.54".to_string(),
+            mark_price: "16596.00".to_string(),
+            bid1_price: "16596.00".to_string(),
+            bid1_size: "1.757".to_string(),
+            ask1_price: "16597.50".to_string(),
+            ask1_size: "0.159".to_string(),
+            volume_24h: "49337318".to_string(),
+            turnover_24h: "2352.94950046".to_string(),
+            high_price_24h: "21128.00".to_string(),
+            low_price_24h: "20590.00".to_string(),
+            price_24h_pcnt: Some("-0.0038".to_string()),
+            prev_price_24h: Some("16660.00".to_string()),
+            open_interest: Some("373504107".to_string()),
+            greeks: None,
+        }
+    }
+
+    #[test]
+    fn test_risk_limit_deserialization() {
+        let json = r#"{
+            "category": "inverse",
+            "list": [
+                {
+                    "id": 1,
+                    "symbol": "BTCUSD",
+                    "riskLimitValue": "150",
+                    "maintenanceMargin": "0.5",
+                    "initialMargin": "1",
+                    "isLowestRisk": 1,
+                    "maxLeverage": "100.00",
+                    "mmDeduction": ""
+                }
+            ],
+            "nextPageCursor": ""
+        }"#;
+
+        let limits: RiskLimitList = serde_json::from_str(json).unwrap();
+        assert_eq!(limits.category, "inverse");
+        let tier = &limits.list[0];
+        assert_eq!(tier.id, 1);
+        assert_eq!(tier.risk_limit_value, "150");
+        assert_eq!(tier.maintenance_margin, "0.5");
+        assert_eq!(tier.initial_margin, "1");
+        assert_eq!(tier.is_lowest_risk, 1);
+        assert_eq!(tier.max_leverage_f64().unwrap(), 100.0);
+    }
+
+    #[test]
+    fn test_risk_limit_tier_for() {
+        let tier = |id: u64, symbol: &str, value: &str, leverage: &str| RiskLimit {
+            id,
+            symbol: symbol.to_string(),
+            risk_limit_value: value.to_string(),
+            maintenance_margin: "0.005".to_string(),
+            initial_margin: "0.01".to_string(),
+            max_leverage: leverage.to_string(),
+            is_lowest_risk: 0,
+        };
+        let limits = RiskLimitList {
+            category: "linear".to_string(),
+            list: vec![
+                tier(2, "BTCUSDT", "2600000", "90.00"),
+                tier(1, "BTCUSDT", "2000000", "100.00"),
+                tier(11, "ETHUSDT", "1000000", "100.00"),
+            ],
+            next_page_cursor: None,
+        };
+
+        assert_eq!(limits.tier_for("BTCUSDT", 1_500_000.0).unwrap().id, 1);
+        assert_eq!(limits.tier_for("BTCUSDT", 2_000_000.0).unwrap().id, 1);
+        assert_eq!(
+            limits
+                .tier_for("BTCUSDT", 2_100_000.0)
+                .unwrap()
+                .max_leverage,
+            "90.00"
+        );
+        assert!(limits.tier_for("BTCUSDT", 3_000_000.0).is_none());
+        assert!(limits.tier_for("SOLUSDT", 1.0).is_none());
+    }
+
+    #[test]
+    fn test_linear_ticker_deserialization() {
+        let json = r#"{
+            "symbol": "BTCUSD",
+            "lastPrice": "16597.00",
+            "indexPrice": "16598.54",
+            "markPrice": "16596.00",
+            "prevPrice24h": "16464.50",
+            "price24hPcnt": "0.008047",
+            "highPrice24h": "30912.50",
+            "lowPrice24h": "15700.00",
+            "prevPrice1h": "16595.50",
+            "openInterest": "373504107",
+            "openInterestValue": "22505.67",
+            "turnover24h": "2352.94950046",
+            "volume24h": "49337318",
+            "fundingRate": "-0.001034",
+            "nextFundingTime": "1672387200000",
+            "predictedDeliveryPrice": "",
+            "basisRate": "",
+            "deliveryFeeRate": "",
+            "deliveryTime": "0",
+            "ask1Size": "1",
+            "bid1Price": "16596.00",
+            "ask1Price": "16597.50",
+            "bid1Size": "1",
+            "basis": ""
+        }"#;
+
+        let ticker: Ticker = serde_json::from_str(json).unwrap();
+        assert_eq!(ticker.volume_24h, "49337318");
+        assert_eq!(ticker.turnover_24h, "2352.94950046");
+        assert_eq!(ticker.high_price_24h, "30912.50");
+        assert_eq!(ticker.low_price_24h, "15700.00");
+        assert_eq!(ticker.price_24h_pcnt.as_deref(), Some("0.008047"));
+        assert_eq!(ticker.prev_price_24h.as_deref(), Some("16464.50"));
+        assert_eq!(ticker.volume_24h_f64().unwrap(), 49337318.0);
+    }
+
+    #[test]
+    fn test_option_ticker_deserialization() {
+        let json = r#"{
+            "symbol": "BTC-30DEC22-18000-C",
+            "bid1Price": "0",
+            "bid1Size": "0",
+            "bid1Iv": "0",
+            "ask1Price": "435",
+            "ask1Size": "0.66",
+            "ask1Iv": "5",
+            "lastPrice": "435",
+            "highPrice24h": "435",
+            "lowPrice24h": "165",
+            "markPrice": "0.00000009",
+            "indexPrice": "16600.55",
+            "markIv": "0.7567",
+            "underlyingPrice": "16590.42",
+            "openInterest": "6.3",
+            "turnover24h": "2482.73",
+            "volume24h": "0.15",
+            "totalVolume": "99",
+            "totalTurnover": "1967653",
+            "delta": "0.00000001",
+            "gamma": "0.00000001",
+            "vega": "0.00000004",
+            "theta": "-0.00000152",
+            "predictedDeliveryPrice": "0",
+            "change24h": "86"
+        }"#;
+
+        let ticker: Ticker = serde_json::from_str(json).unwrap();
+        assert_eq!(ticker.open_interest.as_deref(), Some("6.3"));
+        assert_eq!(ticker.price_24h_pcnt, None);
+        let greeks = ticker.greeks.unwrap();
+        assert_eq!(greeks.theta_f64().unwrap(), -0.00000152);
+        assert_eq!(greeks.mark_iv_f64().unwrap(), 0.7567);
+        assert_eq!(greeks.ask1_iv, "5");
+        assert_eq!(greeks.underlying_price, "16590.42");
+    }
+
+    #[test]
+    fn test_linear_ticker_has_no_greeks() {
+        let json = serde_json::to_string(&ticker()).unwrap();
+        let ticker: Ticker = serde_json::from_str(&json).unwrap();
+        assert!(ticker.greeks.is_none());
+        assert_eq!(ticker.open_interest.as_deref(), Some("373504107"));
+    }
+
+    #[test]
+    fn test_ticker_f64_accessors() {
+        let ticker = ticker();
+        assert_eq!(ticker.last_price_f64().unwrap(), 16597.0);
+        assert_eq!(ticker.mark_price_f64().unwrap(), 16596.0);
+        assert_eq!(ticker.bid1_size_f64().unwrap(), 1.757);
+        assert_eq!(ticker.ask1_price_f64().unwrap(), 16597.5);
+    }
+
+    #[test]
+    fn test_ticker_accessor_reports_malformed_value() {
+        let ticker = Ticker {
+            index_price: String::new(),
+            ..ticker()
+        };
+
+        assert!(matches!(
+            ticker.index_price_f64(),
+            Err(BybitError::ParseError { ref field, ref value }) if field == "index_price" && value.is_empty()
+        ));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_ticker_decimal_accessors() {
+        let ticker = ticker();
+        assert_eq!(
+            ticker.bid1_size_decimal().unwrap(),
+            rust_decimal::Decimal::new(1757, 3)
+        );
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_ticker_spread_helpers() {
+        use rust_decimal::Decimal;
+
+        let ticker = ticker();
+        assert_eq!(ticker.mid_price(), Some(Decimal::new(1659675, 2)));
+        assert_eq!(ticker.spread(), Some(Decimal::new(150, 2)));
+        assert_eq!(
+            ticker.spread_bps().unwrap().round_dp(4),
+            Decimal::new(9038, 4)
+        );
+
+        let empty = Ticker {
+            ask1_price: String::new(),
+            ..ticker
+        };
+        assert_eq!(empty.mid_price(), None);
+        assert_eq!(empty.spread(), None);
+        assert_eq!(empty.spread_bps(), None);
+    }
+
+    #[test]
+    fn test_orderbook_numeric_helpers() {
+        let book = OrderBook {
+            b: vec![
+                ("16493.50".to_string(), "0.006".to_string()),
+                ("16493.00".to_string(), "0.100".to_string()),
+            ],
+            a: vec![("16611.00".to_string(), "0.029".to_string())],
+            ts: 1672765737733,
+            u: 5277055,
+        };
+
+        assert_eq!(book.best_bid(), Some((16493.5, 0.006)));
+        assert_eq!(book.best_ask(), Some((16611.0, 0.029)));
+        assert_eq!(book.mid_price(), Some(16552.25));
+        assert_eq!(book.spread(), Some(117.5));
+    }
+
+    #[test]
+    fn test_orderbook_helpers_handle_empty_and_malformed_levels() {
+        let book = OrderBook {
+            b: vec![("not-a-price".to_string(), "1".to_string())],
+            a: vec![],
+            ts: 0,
+            u: 0,
+        };
+
+        assert_eq!(book.best_bid(), None);
+        assert_eq!(book.best_ask(), None);
+        assert_eq!(book.mid_price(), None);
+        assert_eq!(book.spread(), None);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_orderbook_levels() {
+        use rust_decimal::Decimal;
+
+        let json = r#"{
+            "s": "BTCUSDT",
+            "a": [["16638.64", "0.008479"]],
+            "b": [["16638.27", "0.305749"], ["16638.26", "0.001"]],
+            "ts": 1672765737733,
+            "u": 5277055,
+            "seq": 34545672,
+            "cts": 1672765737729
+        }"#;
+        let book: OrderBook = serde_json::from_str(json).unwrap();
+
+        let (bids, asks) = book.levels().unwrap();
+        assert_eq!(bids.len(), 2);
+        assert_eq!(bids[1].price, Decimal::new(1663826, 2));
+        assert_eq!(asks[0].size, Decimal::new(8479, 6));
+        assert_eq!(book.best_bid_level(), Some(bids[0]));
+        assert_eq!(book.best_ask_level(), Some(asks[0]));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_orderbook_levels_reject_malformed_values() {
+        let book = OrderBook {
+            b: vec![("16493.50".to_string(), "".to_string())],
+            a: vec![],
+            ts: 0,
+            u: 0,
+        };
+
+        assert!(matches!(
+            book.levels(),
+            Err(BybitError::ParseError { ref field, .. }) if field == "bid_size"
+        ));
+        assert_eq!(book.best_bid_level(), None);
+        assert_eq!(book.best_ask_level(), None);
+    }
+
+    #[test]
+    fn test_account_info_deserialization() {
+        let json = r#"{
+            "marginMode": "REGULAR_MARGIN",
+            "updatedTime": "1697078946000",
+            "unifiedMarginStatus": 4,
+            "dcpStatus": "OFF",
+            "timeWindow": 10,
+            "smpGroup": 0,
+            "isMasterTrader": false,
+            "spotHedgingStatus": "OFF"
+        }"#;
+
+        let info: AccountInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(info.unified_margin_status, 4);
+        assert_eq!(info.margin_mode, "REGULAR_MARGIN");
+        assert!(!info.is_master_trader);
+        assert_eq!(info.dcp_status, "OFF");
+        assert_eq!(info.updated_time, "1697078946000");
+        assert!(info.is_unified());
+
+        let classic = AccountInfo {
+            unified_margin_status: 1,
+            ..info
+        };
+        assert!(!classic.is_unified());
+    }
+
+    #[test]
+    fn test_fee_rate_list_deserialization() {
+        let json = r#"{
+            "list": [
+                {"symbol": "ETHUSDT", "takerFeeRate": "0.0006", "makerFeeRate": "0.0001"},
+                {"symbol": "", "baseCoin": "SOL", "takerFeeRate": "0.0003", "makerFeeRate": "0.0003"}
+            ]
+        }"#;
+
+        let fee_rates: FeeRateList = serde_json::from_str(json).unwrap();
+        assert_eq!(fee_rates.list.len(), 2);
+        assert_eq!(fee_rates.list[0].symbol, "ETHUSDT");
+        assert_eq!(fee_rates.list[0].taker_fee_rate, "0.0006");
+        assert_eq!(fee_rates.list[0].maker_fee_rate, "0.0001");
+        assert_eq!(fee_rates.list[0].base_coin, "");
+        assert_eq!(fee_rates.list[1].base_coin, "SOL");
+    }
+
+    #[test]
+    fn test_enums_convert_to_wire_strings() {
+        assert_eq!(String::from(Category::Linear), "linear");
+        assert_eq!(String::from(Side::Sell), "Sell");
+        assert_eq!(String::from(OrderType::Market), "Market");
+        assert_eq!(String::from(TimeInForce::PostOnly), "PostOnly");
+        assert_eq!(String::from(AccountType::Unified), "UNIFIED");
+    }
+
+    #[test]
+    fn test_account_type_try_from() {
+        assert_eq!(
+            AccountType::try_from("CONTRACT").unwrap(),
+            AccountType::Contract
+        );
+        assert!(AccountType::try_from("fund").is_err());
+    }
+
+    /// `as_str` must agree with the `#[serde(rename)]` of every variant
+    #[test]
+    fn test_as_str_matches_serde_names() {
+        fn check<T: Serialize>(value: T, name: &str) {
+            assert_eq!(serde_json::to_value(value).unwrap(), name);
+        }
+
+        for c in [
+            Category::Linear,
+            Category::Inverse,
+            Category::Spot,
+            Category::Option,
+        ] {
+            check(c, c.as_str());
+        }
+        for a in [
+            AccountType::Unified,
+            AccountType::Fund,
+            AccountType::Contract,
+            AccountType::Spot,
+        ] {
+            check(a, a.as_str());
+        }
+        for side in [Side::Buy, Side::Sell] {
+            check(side, side.as_str());
+        }
+        for order_type in [OrderType::Market, OrderType::Limit] {
+            check(order_type, order_type.as_str());
+        }
+        for tif in [
+            TimeInForce::GTC,
+            TimeInForce::IOC,
+            TimeInForce::FOK,
+            TimeInForce::PostOnly,
+            TimeInForce::RPI,
+        ] {
+            check(tif, tif.as_str());
+        }
+    }
+
+    #[test]
+    fn test_create_order_request_builder_with_enums() {
+        let request = CreateOrderRequest::builder()
+            .category(Category::Spot)
+            .symbol("BTCUSDT")
+            .side(Side::Buy)
+            .order_type(OrderType::Limit)
+            .time_in_force(TimeInForce::GTC)
+            .qty("0.001")
+            .price("28000")
+            .build()
+            .unwrap();
+
+        assert_eq!(request.category, "spot");
+        assert_eq!(request.side, "Buy");
+        assert_eq!(request.order_type, "Limit");
+        assert_eq!(request.time_in_force, Some("GTC".to_string()));
+
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(json.contains("\"category\":\"spot\""));
+        assert!(json.contains("\"side\":\"Buy\""));
+        assert!(json.contains("\"orderType\":\"Limit\""));
+        assert!(json.contains("\"timeInForce\":\"GTC\""));
+    }
 }
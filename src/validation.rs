@@ -0,0 +1,506 @@
+//! Client-side order validation against instrument filters
+//!
+//! Checks an order's quantity and price against the lot size and price filters
+//! from [`get_instruments`](crate::BybitClient::get_instruments), so malformed
+//! orders are rejected without a round-trip to Bybit.
+//! [`InstrumentInfo::normalize_qty`] and [`InstrumentInfo::normalize_price`]
+//! turn raw `f64` values into strings that pass those checks.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use rusty_bybit::validation::validate_order;
+//! use rusty_bybit::{BybitClient, CreateOrderRequest};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = BybitClient::testnet();
+//!     let instruments = client.get_instruments("linear", Some("BTCUSDT")).await?;
+//!     let instrument = &instruments.list[0];
+//!
+//!     let request = CreateOrderRequest::builder()
+//!         .symbol("BTCUSDT")
+//!         .side("Buy")
+//!         .order_type("Limit")
+//!         .qty("0.001")
+//!         .price("28000")
+//!         .build()?;
+//!     validate_order(instrument, &request)?;
+//!     Ok(())
+//! }
+//! ```
+
+use std::cmp::Ordering;
+
+use crate::error::{BybitError, Result};
+use crate::types::{CreateOrderRequest, InstrumentInfo};
+
+/// Checks `request` against the filters of `instrument`
+///
+/// `qty` must lie within `[min_order_qty, max_order_qty]` and be a multiple of
+/// `qty_step` (`base_precision` for spot). For limit orders, `price` must be a
+/// multiple of `tick_size` and within `[min_price, max_price]` where the
+/// instrument reports them. Filters the instrument does not report are
+/// skipped, as is the quantity of a spot market order placed in quote coin.
+///
+/// Violations are reported as [`BybitError::InvalidParameter`].
+pub fn validate_order(instrument: &InstrumentInfo, request: &CreateOrderRequest) -> Result<()> {
+    let symbol = &instrument.symbol;
+
+    let qty_in_quote = request.market_unit.as_deref() == Some("quoteCoin");
+    if let (Some(qty), Some(lot), false) = (&request.qty, &instrument.lot_size_filter, qty_in_quote)
+    {
+        let value = Decimal::parse("qty", qty)?;
+        check_range(
+            symbol,
+            "qty",
+            value,
+            Some(("min_order_qty", lot.min_order_qty.as_str())),
+            Some(("max_order_qty", lot.max_order_qty.as_str())),
+        )?;
+        if let Some(step) = &lot.qty_step {
+            check_step(symbol, "qty", value, "qty_step", step)?;
+        } else if let Some(step) = &lot.base_precision {
+            check_step(symbol, "qty", value, "base_precision", step)?;
+        }
+    }
+
+    if request.order_type == "Limit"
+        && let (Some(price), Some(filter)) = (&request.price, &instrument.price_filter)
+    {
+        let value = Decimal::parse("price", price)?;
+        check_range(
+            symbol,
+            "price",
+            value,
+            filter.min_price.as_deref().map(|min| ("min_price", min)),
+            filter.max_price.as_deref().map(|max| ("max_price", max)),
+        )?;
+        check_step(symbol, "price", value, "tick_size", &filter.tick_size)?;
+    }
+
+    Ok(())
+}
+
+/// Rounds `value` to the nearest multiple of `step`, formatted with the step's
+/// number of decimals
+///
+/// The value is read from its shortest `f64` representation and rounded
+/// exactly, so `0.1 + 0.2` with a step of `0.1` gives `"0.3"` rather than
+/// `"0.30000000000000004"`.
+pub fn round_to_step(value: f64, step: &str) -> Result<String> {
+    let value = Decimal::from_f64("value", value)?;
+    let step = Decimal::parse_step("step", step)?;
+    Ok(value.round_to(step, Rounding::Nearest)?.to_string())
+}
+
+impl InstrumentInfo {
+    /// Formats `qty` for an order on this instrument
+    ///
+    /// Rounds down to `qty_step` (`base_precision` for spot), so the order is
+    /// never larger than requested, then clamps to
+    /// `[min_order_qty, max_order_qty]`.
+    pub fn normalize_qty(&self, qty: f64) -> Result<String> {
+        let lot = self.lot_size_filter.as_ref().ok_or_else(|| {
+            BybitError::InvalidParameter(format!("no lot size filter for {}", self.symbol))
+        })?;
+
+        let mut value = Decimal::from_f64("qty", qty)?;
+        if let Some(step) = lot.qty_step.as_ref().or(lot.base_precision.as_ref()) {
+            value = value.round_to(Decimal::parse_step("qty_step", step)?, Rounding::Down)?;
+        }
+        let value = clamp(
+            value,
+            Some(Decimal::parse("min_order_qty", &lot.min_order_qty)?),
+            Some(Decimal::parse("max_order_qty", &lot.max_order_qty)?),
+        );
+        Ok(value.to_string())
+    }
+
+    /// Formats `price` for an order on this instrument
+    ///
+    /// Rounds to the nearest `tick_size`, then clamps to `[min_price,
+    /// max_price]` where the instrument reports them.
+    pub fn normalize_price(&self, price: f64) -> Result<String> {
+        let filter = self.price_filter.as_ref().ok_or_else(|| {
+            BybitError::InvalidParameter(format!("no price filter for {}", self.symbol))
+        })?;
+
+        let tick_size = Decimal::parse_step("tick_size", &filter.tick_size)?;
+        let value = Decimal::from_f64("price", price)?.round_to(tick_size, Rounding::Nearest)?;
+        let min = filter
+            .min_price
+            .as_deref()
+            .map(|m| Decimal::parse("min_price", m));
+        let max = filter
+            .max_price
+            .as_deref()
+            .map(|m| Decimal::parse("max_price", m));
+        Ok(clamp(value, min.transpose()?, max.transpose()?).to_string())
+    }
+}
+
+fn clamp(value: Decimal, min: Option<Decimal>, max: Option<Decimal>) -> Decimal {
+    match (min, max) {
+        (Some(min), _) if value.cmp(&min) == Ordering::Less => min,
+        (_, Some(max)) if value.cmp(&max) == Ordering::Greater => max,
+        _ => value,
+    }
+}
+
+fn check_range(
+    symbol: &str,
+    field: &str,
+    value: Decimal,
+    min: Option<(&str, &str)>,
+    max: Option<(&str, &str)>,
+) -> Result<()> {
+    if let Some((name, min)) = min
+        && value.cmp(&Decimal::parse(name, min)?) == Ordering::Less
+    {
+        return Err(BybitError::InvalidParameter(format!(
+            "{} {} is below {} {} for {}",
+            field, value, name, min, symbol
+        )));
+    }
+    if let Some((name, max)) = max
+        && value.cmp(&Decimal::parse(name, max)?) == Ordering::Greater
+    {
+        return Err(BybitError::InvalidParameter(format!(
+            "{} {} is above {} {} for {}",
+            field, value, name, max, symbol
+        )));
+    }
+    Ok(())
+}
+
+fn check_step(symbol: &str, field: &str, value: Decimal, name: &str, step: &str) -> Result<()> {
+    let step_value = Decimal::parse(name, step)?;
+    if !value.is_multiple_of(step_value) {
+        return Err(BybitError::InvalidParameter(format!(
+            "{} {} is not a multiple of {} {} for {}",
+            field, value, name, step, symbol
+        )));
+    }
+    Ok(())
+}
+
+/// Exact decimal parsed from Bybit's string-encoded numbers, as
+/// `mantissa * 10^-scale`
+#[derive(Debug, Clone, Copy)]
+struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    fn parse(field: &str, s: &str) -> Result<Self> {
+        let invalid = || BybitError::InvalidParameter(format!("invalid {} '{}'", field, s));
+
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(invalid());
+        }
+        if !int_part
+            .bytes()
+            .chain(frac_part.bytes())
+            .all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let mut mantissa: i128 = 0;
+        for b in int_part.bytes().chain(frac_part.bytes()) {
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(i128::from(b - b'0')))
+                .ok_or_else(invalid)?;
+        }
+
+        Ok(Decimal {
+            mantissa: if negative { -mantissa } else { mantissa },
+            scale: frac_part.len() as u32,
+        })
+    }
+
+    /// Mantissas of `self` and `other` brought to a common scale
+    fn aligned(self, other: Decimal) -> Option<(i128, i128)> {
+        let scale = self.scale.max(other.scale);
+        let a = self
+            .mantissa
+            .checked_mul(10_i128.checked_pow(scale - self.scale)?)?;
+        let b = other
+            .mantissa
+            .checked_mul(10_i128.checked_pow(scale - other.scale)?)?;
+        Some((a, b))
+    }
+
+    fn cmp(self, other: &Decimal) -> Ordering {
+        match self.aligned(*other) {
+            Some((a, b)) => a.cmp(&b),
+            // Only reachable for absurd scales; compare the integer parts instead.
+            None => (self.mantissa / 10_i128.pow(self.scale.min(38)))
+                .cmp(&(other.mantissa / 10_i128.pow(other.scale.min(38)))),
+        }
+    }
+
+    /// Reads an `f64` through its shortest round-trip representation
+    fn from_f64(field: &str, value: f64) -> Result<Self> {
+        Decimal::parse(field, &value.to_string())
+    }
+
+    /// Like `parse`, additionally requiring a positive value
+    fn parse_step(field: &str, s: &str) -> Result<Self> {
+        let step = Decimal::parse(field, s)?;
+        if step.mantissa <= 0 {
+            return Err(BybitError::InvalidParameter(format!(
+                "{} must be positive, got '{}'",
+                field, s
+            )));
+        }
+        Ok(step)
+    }
+
+    /// Multiple of `step` nearest to `self` (or the one below, for
+    /// `Rounding::Down`), at the step's scale
+    fn round_to(self, step: Decimal, rounding: Rounding) -> Result<Decimal> {
+        let overflow = || BybitError::InvalidParameter(format!("{} is out of range", self));
+        let (value, step_value) = self.aligned(step).ok_or_else(overflow)?;
+        let steps = match rounding {
+            Rounding::Down => value.div_euclid(step_value),
+            Rounding::Nearest => value
+                .checked_mul(2)
+                .and_then(|v| v.checked_add(step_value))
+                .ok_or_else(overflow)?
+                .div_euclid(step_value * 2),
+        };
+        Ok(Decimal {
+            mantissa: steps.checked_mul(step.mantissa).ok_or_else(overflow)?,
+            scale: step.scale,
+        })
+    }
+
+    fn is_multiple_of(self, step: Decimal) -> bool {
+        match self.aligned(step) {
+            Some((_, 0)) | None => true,
+            Some((value, step)) => value % step == 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Rounding {
+    Down,
+    Nearest,
+}
+
+impl std::fmt::Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        if scale == 0 {
+            return write!(f, "{}{}", sign, digits);
+        }
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+        write!(f, "{}{}.{}", sign, int_part, frac_part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{LotSizeFilter, PriceFilter};
+
+    fn instrument() -> InstrumentInfo {
+        InstrumentInfo {
+            symbol: "BTCUSDT".to_string(),
+            contract_type: "LinearPerpetual".to_string(),
+            status: "Trading".to_string(),
+            base_coin: "BTC".to_string(),
+            quote_coin: "USDT".to_string(),
+            settle_coin: "USDT".to_string(),
+            price_scale: "2".to_string(),
+            lot_size_filter: Some(LotSizeFilter {
+                min_order_qty: "0.001".to_string(),
+                max_order_qty: "100.000".to_string(),
+                qty_step: Some("0.001".to_string()),
+                min_notional_value: Some("5".to_string()),
+                base_precision: None,
+            }),
+            price_filter: Some(PriceFilter {
+                min_price: Some("0.10".to_string()),
+                max_price: Some("199999.80".to_string()),
+                tick_size: "0.10".to_string(),
+            }),
+        }
+    }
+
+    fn limit_order(qty: &str, price: &str) -> CreateOrderRequest {
+        CreateOrderRequest::builder()
+            .symbol("BTCUSDT")
+            .side("Buy")
+            .order_type("Limit")
+            .qty(qty)
+            .price(price)
+            .build()
+            .unwrap()
+    }
+
+    fn assert_rejected(request: &CreateOrderRequest, expected: &str) {
+        match validate_order(&instrument(), request) {
+            Err(BybitError::InvalidParameter(msg)) => assert_eq!(msg, expected),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_valid_limit_order() {
+        assert!(validate_order(&instrument(), &limit_order("0.015", "28000.1")).is_ok());
+        assert!(validate_order(&instrument(), &limit_order("100", "0.1")).is_ok());
+    }
+
+    #[test]
+    fn test_qty_below_min() {
+        assert_rejected(
+            &limit_order("0.0001", "28000"),
+            "qty 0.0001 is below min_order_qty 0.001 for BTCUSDT",
+        );
+    }
+
+    #[test]
+    fn test_qty_above_max() {
+        assert_rejected(
+            &limit_order("100.001", "28000"),
+            "qty 100.001 is above max_order_qty 100.000 for BTCUSDT",
+        );
+    }
+
+    #[test]
+    fn test_qty_not_multiple_of_step() {
+        assert_rejected(
+            &limit_order("0.0015", "28000"),
+            "qty 0.0015 is not a multiple of qty_step 0.001 for BTCUSDT",
+        );
+    }
+
+    #[test]
+    fn test_price_below_min() {
+        assert_rejected(
+            &limit_order("0.001", "0.05"),
+            "price 0.05 is below min_price 0.10 for BTCUSDT",
+        );
+    }
+
+    #[test]
+    fn test_price_above_max() {
+        assert_rejected(
+            &limit_order("0.001", "200000"),
+            "price 200000 is above max_price 199999.80 for BTCUSDT",
+        );
+    }
+
+    #[test]
+    fn test_price_not_multiple_of_tick_size() {
+        assert_rejected(
+            &limit_order("0.001", "28000.05"),
+            "price 28000.05 is not a multiple of tick_size 0.10 for BTCUSDT",
+        );
+    }
+
+    #[test]
+    fn test_unparseable_number() {
+        assert_rejected(&limit_order("1e-3", "28000"), "invalid qty '1e-3'");
+    }
+
+    #[test]
+    fn test_market_order_price_is_ignored() {
+        let request = CreateOrderRequest::builder()
+            .symbol("BTCUSDT")
+            .side("Sell")
+            .order_type("Market")
+            .qty("0.001")
+            .price("28000.05")
+            .build()
+            .unwrap();
+        assert!(validate_order(&instrument(), &request).is_ok());
+    }
+
+    #[test]
+    fn test_round_to_step() {
+        assert_eq!(round_to_step(0.1 + 0.2, "0.1").unwrap(), "0.3");
+        assert_eq!(round_to_step(0.0015, "0.001").unwrap(), "0.002");
+        assert_eq!(round_to_step(28000.04, "0.10").unwrap(), "28000.00");
+        assert_eq!(round_to_step(1234.0, "5").unwrap(), "1235");
+        assert!(matches!(
+            round_to_step(1.0, "0"),
+            Err(BybitError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            round_to_step(f64::NAN, "0.1"),
+            Err(BybitError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_normalize_qty() {
+        let instrument = instrument();
+        assert_eq!(instrument.normalize_qty(0.1 + 0.2).unwrap(), "0.300");
+        assert_eq!(instrument.normalize_qty(0.0159).unwrap(), "0.015");
+        assert_eq!(instrument.normalize_qty(0.0004).unwrap(), "0.001");
+        assert_eq!(instrument.normalize_qty(250.0).unwrap(), "100.000");
+
+        let qty = instrument.normalize_qty(0.0159).unwrap();
+        assert!(validate_order(&instrument, &limit_order(&qty, "28000")).is_ok());
+    }
+
+    #[test]
+    fn test_normalize_price() {
+        let instrument = instrument();
+        assert_eq!(instrument.normalize_price(28000.06).unwrap(), "28000.10");
+        assert_eq!(instrument.normalize_price(28000.04).unwrap(), "28000.00");
+        assert_eq!(instrument.normalize_price(0.01).unwrap(), "0.10");
+        assert_eq!(instrument.normalize_price(250000.0).unwrap(), "199999.80");
+    }
+
+    #[test]
+    fn test_normalize_without_filters() {
+        let mut instrument = instrument();
+        instrument.lot_size_filter = None;
+        instrument.price_filter = None;
+        assert!(matches!(
+            instrument.normalize_qty(1.0),
+            Err(BybitError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            instrument.normalize_price(1.0),
+            Err(BybitError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_spot_base_precision_used_as_step() {
+        let mut spot = instrument();
+        spot.lot_size_filter = Some(LotSizeFilter {
+            min_order_qty: "0.000048".to_string(),
+            max_order_qty: "71.73956243".to_string(),
+            qty_step: None,
+            min_notional_value: None,
+            base_precision: Some("0.000001".to_string()),
+        });
+
+        let request = limit_order("0.0000485", "28000");
+        match validate_order(&spot, &request) {
+            Err(BybitError::InvalidParameter(msg)) => assert_eq!(
+                msg,
+                "qty 0.0000485 is not a multiple of base_precision 0.000001 for BTCUSDT"
+            ),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
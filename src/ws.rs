@@ -0,0 +1,948 @@
+//! WebSocket streaming client for Bybit v5 API
+//!
+//! Connects to the v5 public and private streams and yields parsed pushes through
+//! a [`futures_util::Stream`]. The connection is driven by a background task that
+//! answers ping frames, sends the heartbeat Bybit expects every 20 seconds and
+//! reconnects (re-authenticating and resubscribing) when the socket drops.
+//!
+//! # Authentication
+//!
+//! The private stream is opened with [`BybitWsClient::connect_private`], which
+//! performs the `auth` operation with a signature over `GET/realtime{expires}`.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use futures_util::StreamExt;
+//! use rusty_bybit::ws::{BybitWsClient, PublicMessage};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let mut stream = BybitWsClient::testnet().connect("linear").await.unwrap();
+//!     stream
+//!         .subscribe(&["orderbook.50.BTCUSDT", "publicTrade.BTCUSDT", "tickers.BTCUSDT"])
+//!         .unwrap();
+//!
+//!     while let Some(message) = stream.next().await {
+//!         match message.unwrap() {
+//!             PublicMessage::OrderBook(book) => println!("{:?} seq {}", book.kind, book.seq),
+//!             PublicMessage::Trades(trades) => println!("{} trades", trades.len()),
+//!             PublicMessage::Ticker(ticker) => println!("last {:?}", ticker.last_price),
+//!             PublicMessage::Other { topic, .. } => println!("push on {}", topic),
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_util::{SinkExt, Stream, StreamExt};
+use serde::Deserialize;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::auth::{Credentials, get_current_timestamp_ms};
+use crate::error::{BybitError, Result};
+use crate::types::{
+    AccountBalance, Execution, Order, OrderBookUpdate, Position, PublicTrade, TickerUpdate,
+    UpdateKind,
+};
+
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(20);
+const RECONNECT_BASE_DELAY: Duration = Duration::from_secs(1);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+const AUTH_TIMEOUT: Duration = Duration::from_secs(10);
+const AUTH_EXPIRY_MS: i64 = 10_000;
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+type Parser<M> = fn(serde_json::Value) -> Option<Result<M>>;
+
+/// Parsed push from a public stream
+#[derive(Debug, Clone)]
+pub enum PublicMessage {
+    OrderBook(OrderBookUpdate),
+    Trades(Vec<PublicTrade>),
+    Ticker(Box<TickerUpdate>),
+    /// Push on a topic without a typed representation (kline, liquidation, ...)
+    Other {
+        topic: String,
+        data: serde_json::Value,
+    },
+}
+
+/// Parsed push from the private stream
+#[derive(Debug, Clone)]
+pub enum PrivateMessage {
+    Order(Vec<Order>),
+    Position(Vec<Position>),
+    Execution(Vec<Execution>),
+    Wallet(Vec<AccountBalance>),
+    /// Push on a topic without a typed representation (greeks, dcp, ...)
+    Other {
+        topic: String,
+        data: serde_json::Value,
+    },
+}
+
+/// Factory for WebSocket connections against a Bybit environment
+#[derive(Debug, Clone)]
+pub struct BybitWsClient {
+    pub base_url: String,
+}
+
+impl BybitWsClient {
+    /// `base_url` is the stream host, e.g. `wss://stream.bybit.com`
+    pub fn new(base_url: String) -> Self {
+        Self { base_url }
+    }
+
+    pub fn testnet() -> Self {
+        Self::new("wss://stream-testnet.bybit.com".to_string())
+    }
+
+    pub fn mainnet() -> Self {
+        Self::new("wss://stream.bybit.com".to_string())
+    }
+
+    /// Opens the public stream for `category` (`linear`, `inverse`, `spot` or `option`)
+    pub async fn connect(&self, category: &str) -> Result<WsStream<PublicMessage>> {
+        let url = format!("{}/v5/public/{}", self.base_url, category);
+        WsStream::open(url, None, parse_public).await
+    }
+
+    /// Opens and authenticates the private stream
+    ///
+    /// Topics of interest are `order`, `position`, `execution` and `wallet`.
+    /// A rejected `auth` operation is reported as [`BybitError::AuthenticationError`].
+    pub async fn connect_private(
+        &self,
+        credentials: Credentials,
+    ) -> Result<WsStream<PrivateMessage>> {
+        let url = format!("{}/v5/private", self.base_url);
+        WsStream::open(url, Some(credentials), parse_private).await
+    }
+}
+
+enum Command {
+    Subscribe(Vec<String>),
+    Unsubscribe(Vec<String>),
+}
+
+/// Live WebSocket connection yielding parsed messages
+///
+/// Dropping the stream closes the socket and stops the background task.
+pub struct WsStream<M> {
+    commands: mpsc::UnboundedSender<Command>,
+    messages: mpsc::UnboundedReceiver<Result<M>>,
+}
+
+impl<M: Send + 'static> WsStream<M> {
+    async fn open(
+        url: String,
+        credentials: Option<Credentials>,
+        parser: Parser<M>,
+    ) -> Result<Self> {
+        let (commands, command_rx) = mpsc::unbounded_channel();
+        let (message_tx, messages) = mpsc::unbounded_channel();
+
+        let session = Session {
+            url,
+            credentials,
+            topics: Vec::new(),
+            parser,
+            messages: message_tx,
+        };
+        let socket = session.open_socket().await?;
+        tokio::spawn(session.run(socket, command_rx));
+
+        Ok(Self { commands, messages })
+    }
+
+    /// Subscribes to `topics`; they are resubscribed automatically after a reconnect
+    pub fn subscribe(&self, topics: &[&str]) -> Result<()> {
+        self.send(Command::Subscribe(to_owned(topics)))
+    }
+
+    pub fn unsubscribe(&self, topics: &[&str]) -> Result<()> {
+        self.send(Command::Unsubscribe(to_owned(topics)))
+    }
+
+    fn send(&self, command: Command) -> Result<()> {
+        self.commands
+            .send(command)
+            .map_err(|_| BybitError::WebSocketError("connection closed".to_string()))
+    }
+}
+
+impl<M> Stream for WsStream<M> {
+    type Item = Result<M>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().messages.poll_recv(cx)
+    }
+}
+
+enum Exit {
+    Disconnected,
+    Closed,
+}
+
+struct Session<M> {
+    url: String,
+    credentials: Option<Credentials>,
+    topics: Vec<String>,
+    parser: Parser<M>,
+    messages: mpsc::UnboundedSender<Result<M>>,
+}
+
+impl<M: Send + 'static> Session<M> {
+    async fn run(mut self, mut socket: Socket, mut commands: mpsc::UnboundedReceiver<Command>) {
+        loop {
+            if let Exit::Closed = self.drive(&mut socket, &mut commands).await {
+                let _ = socket.close(None).await;
+                return;
+            }
+
+            socket = match self.reconnect().await {
+                Some(socket) => socket,
+                None => return,
+            };
+        }
+    }
+
+    async fn drive(
+        &mut self,
+        socket: &mut Socket,
+        commands: &mut mpsc::UnboundedReceiver<Command>,
+    ) -> Exit {
+        let mut heartbeat = tokio::time::interval(HEARTBEAT_INTERVAL);
+        heartbeat.tick().await;
+
+        loop {
+            tokio::select! {
+                command = commands.recv() => {
+                    let sent = match command {
+                        None => return Exit::Closed,
+                        Some(Command::Subscribe(topics)) => {
+                            let sent = send_op(socket, "subscribe", &topics).await;
+                            for topic in topics {
+                                if !self.topics.contains(&topic) {
+                                    self.topics.push(topic);
+                                }
+                            }
+                            sent
+                        }
+                        Some(Command::Unsubscribe(topics)) => {
+                            self.topics.retain(|t| !topics.contains(t));
+                            send_op(socket, "unsubscribe", &topics).await
+                        }
+                    };
+                    if sent.is_err() {
+                        return Exit::Disconnected;
+                    }
+                }
+                frame = socket.next() => match frame {
+                    Some(Ok(Message::Text(text))) => {
+                        if !self.dispatch(text.as_str()) {
+                            return Exit::Closed;
+                        }
+                    }
+                    Some(Ok(Message::Ping(payload))) => {
+                        if socket.send(Message::Pong(payload)).await.is_err() {
+                            return Exit::Disconnected;
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => {
+                        return Exit::Disconnected;
+                    }
+                    Some(Ok(_)) => {}
+                },
+                _ = heartbeat.tick() => {
+                    let ping = serde_json::json!({ "op": "ping" }).to_string();
+                    if socket.send(Message::text(ping)).await.is_err() {
+                        return Exit::Disconnected;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parses a text frame and forwards it; returns `false` once the consumer is gone
+    fn dispatch(&self, text: &str) -> bool {
+        let message = match serde_json::from_str::<serde_json::Value>(text) {
+            Ok(value) => (self.parser)(value),
+            Err(e) => Some(Err(e.into())),
+        };
+
+        match message {
+            Some(message) => self.messages.send(message).is_ok(),
+            None => !self.messages.is_closed(),
+        }
+    }
+
+    async fn open_socket(&self) -> Result<Socket> {
+        let mut socket = connect_socket(&self.url).await?;
+        if let Some(credentials) = &self.credentials {
+            authenticate(&mut socket, credentials).await?;
+        }
+        Ok(socket)
+    }
+
+    async fn reconnect(&self) -> Option<Socket> {
+        let mut delay = RECONNECT_BASE_DELAY;
+
+        loop {
+            if self.messages.is_closed() {
+                return None;
+            }
+
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(RECONNECT_MAX_DELAY);
+
+            let mut socket = match self.open_socket().await {
+                Ok(socket) => socket,
+                Err(e @ BybitError::AuthenticationError(_)) => {
+                    let _ = self.messages.send(Err(e));
+                    return None;
+                }
+                Err(_) => continue,
+            };
+            if self.topics.is_empty()
+                || send_op(&mut socket, "subscribe", &self.topics)
+                    .await
+                    .is_ok()
+            {
+                return Some(socket);
+            }
+        }
+    }
+}
+
+async fn connect_socket(url: &str) -> Result<Socket> {
+    let (socket, _) = tokio_tungstenite::connect_async(url)
+        .await
+        .map_err(|e| BybitError::WebSocketError(e.to_string()))?;
+    Ok(socket)
+}
+
+async fn send_op(socket: &mut Socket, op: &str, args: &[String]) -> Result<()> {
+    let request = serde_json::json!({ "op": op, "args": args }).to_string();
+    socket
+        .send(Message::text(request))
+        .await
+        .map_err(|e| BybitError::WebSocketError(e.to_string()))
+}
+
+async fn authenticate(socket: &mut Socket, credentials: &Credentials) -> Result<()> {
+    let expires = get_current_timestamp_ms() + AUTH_EXPIRY_MS;
+    let request = auth_request(credentials, expires)?;
+    socket
+        .send(Message::text(request.to_string()))
+        .await
+        .map_err(|e| BybitError::WebSocketError(e.to_string()))?;
+
+    let response = tokio::time::timeout(AUTH_TIMEOUT, read_op_response(socket, "auth"))
+        .await
+        .map_err(|_| {
+            BybitError::AuthenticationError("no response to auth request".to_string())
+        })??;
+
+    if response.success != Some(true) {
+        return Err(BybitError::AuthenticationError(response.ret_msg));
+    }
+    Ok(())
+}
+
+/// Builds the `auth` operation frame for a request valid until `expires`
+fn auth_request(credentials: &Credentials, expires: i64) -> Result<serde_json::Value> {
+    let signature = credentials.sign_ws(expires)?;
+    Ok(serde_json::json!({
+        "op": "auth",
+        "args": [credentials.api_key, expires, signature],
+    }))
+}
+
+/// Reads frames until the acknowledgement for `op` arrives
+async fn read_op_response(socket: &mut Socket, op: &str) -> Result<OpResponse> {
+    while let Some(frame) = socket.next().await {
+        match frame.map_err(|e| BybitError::WebSocketError(e.to_string()))? {
+            Message::Text(text) => {
+                let value: serde_json::Value = serde_json::from_str(text.as_str())?;
+                if value.get("op").and_then(|v| v.as_str()) == Some(op) {
+                    return Ok(OpResponse::deserialize(value)?);
+                }
+            }
+            Message::Ping(payload) => socket
+                .send(Message::Pong(payload))
+                .await
+                .map_err(|e| BybitError::WebSocketError(e.to_string()))?,
+            Message::Close(_) => break,
+            _ => {}
+        }
+    }
+    Err(BybitError::WebSocketError(format!(
+        "connection closed while waiting for {} response",
+        op
+    )))
+}
+
+fn to_owned(topics: &[&str]) -> Vec<String> {
+    topics.iter().map(|t| t.to_string()).collect()
+}
+
+#[derive(Deserialize)]
+struct OpResponse {
+    #[serde(default)]
+    success: Option<bool>,
+    #[serde(default)]
+    ret_msg: String,
+    op: String,
+}
+
+#[derive(Deserialize)]
+struct TopicPush {
+    topic: String,
+    #[serde(rename = "type", default)]
+    kind: Option<UpdateKind>,
+    #[serde(default)]
+    ts: i64,
+    data: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct OrderBookData {
+    s: String,
+    b: Vec<(String, String)>,
+    a: Vec<(String, String)>,
+    u: i64,
+    seq: i64,
+}
+
+/// Checks an operation acknowledgement (`subscribe`, `ping`, ...) and turns a
+/// rejection into an error. Successful acknowledgements carry no data.
+fn check_ack(value: serde_json::Value) -> Result<()> {
+    let response = OpResponse::deserialize(value)?;
+    if response.success == Some(false) {
+        return Err(BybitError::WebSocketError(format!(
+            "{} failed: {}",
+            response.op, response.ret_msg
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) fn parse_public(value: serde_json::Value) -> Option<Result<PublicMessage>> {
+    if value.get("op").is_some() {
+        return check_ack(value).err().map(Err);
+    }
+
+    let push = match TopicPush::deserialize(value) {
+        Ok(push) => push,
+        Err(e) => return Some(Err(e.into())),
+    };
+
+    let message = if push.topic.starts_with("orderbook.") {
+        serde_json::from_value::<OrderBookData>(push.data).map(|data| {
+            PublicMessage::OrderBook(OrderBookUpdate {
+                symbol: data.s,
+                kind: push.kind.unwrap_or(UpdateKind::Snapshot),
+                bids: data.b,
+                asks: data.a,
+                update_id: data.u,
+                seq: data.seq,
+                ts: push.ts,
+            })
+        })
+    } else if push.topic.starts_with("publicTrade.") {
+        serde_json::from_value(push.data).map(PublicMessage::Trades)
+    } else if push.topic.starts_with("tickers.") {
+        serde_json::from_value::<TickerUpdate>(push.data).map(|ticker| {
+            PublicMessage::Ticker(Box::new(TickerUpdate {
+                kind: push.kind.unwrap_or_default(),
+                ts: push.ts,
+                ..ticker
+            }))
+        })
+    } else {
+        Ok(PublicMessage::Other {
+            topic: push.topic,
+            data: push.data,
+        })
+    };
+
+    Some(message.map_err(BybitError::from))
+}
+
+pub(crate) fn parse_private(value: serde_json::Value) -> Option<Result<PrivateMessage>> {
+    if value.get("op").is_some() {
+        return check_ack(value).err().map(Err);
+    }
+
+    let push = match TopicPush::deserialize(value) {
+        Ok(push) => push,
+        Err(e) => return Some(Err(e.into())),
+    };
+
+    // Category-scoped topics such as `order.linear` share the payload of the
+    // all-in-one topic.
+    let message = match push.topic.split('.').next().unwrap_or_default() {
+        "order" => serde_json::from_value(push.data).map(PrivateMessage::Order),
+        "position" => serde_json::from_value(push.data).map(PrivateMessage::Position),
+        "execution" => serde_json::from_value(push.data).map(PrivateMessage::Execution),
+        "wallet" => serde_json::from_value(push.data).map(PrivateMessage::Wallet),
+        _ => Ok(PrivateMessage::Other {
+            topic: push.topic,
+            data: push.data,
+        }),
+    };
+
+    Some(message.map_err(BybitError::from))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> Option<Result<PublicMessage>> {
+        parse_public(serde_json::from_str(json).unwrap())
+    }
+
+    #[test]
+    fn test_parse_orderbook_snapshot() {
+        let json = r#"{
+            "topic": "orderbook.50.BTCUSDT",
+            "type": "snapshot",
+            "ts": 1672304484978,
+            "data": {
+                "s": "BTCUSDT",
+                "b": [["16493.50", "0.006"], ["16493.00", "0.100"]],
+                "a": [["16611.00", "0.029"]],
+                "u": 18521288,
+                "seq": 7961638724
+            },
+            "cts": 1672304484976
+        }"#;
+
+        match parse(json).unwrap().unwrap() {
+            PublicMessage::OrderBook(book) => {
+                assert_eq!(book.symbol, "BTCUSDT");
+                assert_eq!(book.kind, UpdateKind::Snapshot);
+                assert_eq!(book.bids.len(), 2);
+                assert_eq!(book.asks[0], ("16611.00".to_string(), "0.029".to_string()));
+                assert_eq!(book.update_id, 18521288);
+                assert_eq!(book.seq, 7961638724);
+                assert_eq!(book.ts, 1672304484978);
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_orderbook_delta() {
+        let json = r#"{
+            "topic": "orderbook.50.BTCUSDT",
+            "type": "delta",
+            "ts": 1687940967466,
+            "data": {
+                "s": "BTCUSDT",
+                "b": [["30247.20", "0"]],
+                "a": [],
+                "u": 177400507,
+                "seq": 66544703342
+            }
+        }"#;
+
+        match parse(json).unwrap().unwrap() {
+            PublicMessage::OrderBook(book) => {
+                assert_eq!(book.kind, UpdateKind::Delta);
+                assert_eq!(book.bids[0].1, "0");
+                assert!(book.asks.is_empty());
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_public_trades() {
+        let json = r#"{
+            "topic": "publicTrade.BTCUSDT",
+            "type": "snapshot",
+            "ts": 1672304486868,
+            "data": [{
+                "T": 1672304486865,
+                "s": "BTCUSDT",
+                "S": "Buy",
+                "v": "0.001",
+                "p": "16578.50",
+                "L": "PlusTick",
+                "i": "20f43950-d8dd-5b31-9112-a178eb6023af",
+                "BT": false
+            }]
+        }"#;
+
+        match parse(json).unwrap().unwrap() {
+            PublicMessage::Trades(trades) => {
+                assert_eq!(trades.len(), 1);
+                assert_eq!(trades[0].side, "Buy");
+                assert_eq!(trades[0].price, "16578.50");
+                assert_eq!(trades[0].tick_direction.as_deref(), Some("PlusTick"));
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_ticker_delta() {
+        let json = r#"{
+            "topic": "tickers.BTCUSDT",
+            "type": "delta",
+            "data": {
+                "symbol": "BTCUSDT",
+                "bid1Price": "17215.50",
+                "bid1Size": "84.489",
+                "ask1Price": "17216.00",
+                "ask1Size": "83.020"
+            },
+            "cs": 24987956059,
+            "ts": 1673272861686
+        }"#;
+
+        match parse(json).unwrap().unwrap() {
+            PublicMessage::Ticker(ticker) => {
+                assert_eq!(ticker.symbol, "BTCUSDT");
+                assert_eq!(ticker.kind, UpdateKind::Delta);
+                assert_eq!(ticker.ts, 1673272861686);
+                assert_eq!(ticker.bid1_price.as_deref(), Some("17215.50"));
+                assert!(ticker.last_price.is_none());
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_untyped_topic() {
+        let json = r#"{"topic":"kline.5.BTCUSDT","type":"snapshot","ts":1,"data":[{"start":1}]}"#;
+
+        match parse(json).unwrap().unwrap() {
+            PublicMessage::Other { topic, data } => {
+                assert_eq!(topic, "kline.5.BTCUSDT");
+                assert_eq!(data[0]["start"], 1);
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_successful_ack_is_dropped() {
+        let subscribed =
+            r#"{"success":true,"ret_msg":"","conn_id":"abc","req_id":"","op":"subscribe"}"#;
+        assert!(parse(subscribed).is_none());
+
+        let pong = r#"{"success":true,"ret_msg":"pong","conn_id":"abc","op":"ping"}"#;
+        assert!(parse(pong).is_none());
+    }
+
+    #[test]
+    fn test_parse_failed_subscription() {
+        let json = r#"{"success":false,"ret_msg":"error:handler not found","conn_id":"abc","op":"subscribe"}"#;
+
+        match parse(json).unwrap() {
+            Err(BybitError::WebSocketError(msg)) => {
+                assert!(msg.contains("subscribe"));
+                assert!(msg.contains("handler not found"));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resubscribes_after_reconnect() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                let (tcp, _) = listener.accept().await.unwrap();
+                let mut socket = tokio_tungstenite::accept_async(tcp).await.unwrap();
+                let Some(Ok(Message::Text(request))) = socket.next().await else {
+                    panic!("expected subscribe request");
+                };
+                requests.push(request.to_string());
+                let push = r#"{"topic":"publicTrade.BTCUSDT","ts":1,"data":[{"T":1,"s":"BTCUSDT","S":"Sell","v":"1","p":"2","i":"x"}]}"#;
+                socket.send(Message::text(push)).await.unwrap();
+                socket.close(None).await.unwrap();
+            }
+            requests
+        });
+
+        let client = BybitWsClient::new(format!("ws://{}", addr));
+        let mut stream = client.connect("linear").await.unwrap();
+        stream.subscribe(&["publicTrade.BTCUSDT"]).unwrap();
+
+        for _ in 0..2 {
+            let message = stream.next().await.unwrap().unwrap();
+            assert!(
+                matches!(message, PublicMessage::Trades(ref trades) if trades[0].side == "Sell")
+            );
+        }
+
+        let requests = server.await.unwrap();
+        for request in requests {
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            assert_eq!(request["op"], "subscribe");
+            assert_eq!(request["args"][0], "publicTrade.BTCUSDT");
+        }
+    }
+
+    #[test]
+    fn test_parse_private_order() {
+        let json = r#"{
+            "id": "5923240c6880ab-c59f-420b-9adb-3639adc9dd90",
+            "topic": "order",
+            "creationTime": 1672364262474,
+            "data": [{
+                "symbol": "ETH-30DEC22-1400-C",
+                "orderId": "5cf98598-39a7-459e-97bf-76ca765ee020",
+                "side": "Sell",
+                "orderType": "Market",
+                "cancelType": "UNKNOWN",
+                "price": "72.5",
+                "qty": "1",
+                "orderIv": "",
+                "timeInForce": "IOC",
+                "orderStatus": "Filled",
+                "orderLinkId": "",
+                "lastPriceOnCreated": "",
+                "reduceOnly": false,
+                "leavesQty": "",
+                "leavesValue": "",
+                "cumExecQty": "1",
+                "cumExecValue": "75",
+                "avgPrice": "75",
+                "blockTradeId": "",
+                "positionIdx": 0,
+                "cumExecFee": "0.358635",
+                "createdTime": "1672364262444",
+                "updatedTime": "1672364262457",
+                "rejectReason": "EC_NoError",
+                "stopOrderType": "",
+                "triggerPrice": "",
+                "takeProfit": "",
+                "stopLoss": "",
+                "tpTriggerBy": "",
+                "slTriggerBy": "",
+                "triggerDirection": 0,
+                "triggerBy": "",
+                "closeOnTrigger": false,
+                "category": "option",
+                "placeType": "price",
+                "smpType": "None",
+                "smpGroup": 0,
+                "smpOrderId": "",
+                "createType": "CreateByUser"
+            }]
+        }"#;
+
+        match parse_private(serde_json::from_str(json).unwrap())
+            .unwrap()
+            .unwrap()
+        {
+            PrivateMessage::Order(orders) => {
+                assert_eq!(orders[0].order_id, "5cf98598-39a7-459e-97bf-76ca765ee020");
+                assert_eq!(orders[0].status, "Filled");
+                assert_eq!(orders[0].cum_exec_qty, "1");
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_private_wallet() {
+        let json = r#"{
+            "id": "592324d2bce751-ad38-48eb-8f42-4671d1fb4d4e",
+            "topic": "wallet",
+            "creationTime": 1700034722104,
+            "data": [{
+                "accountIMRate": "0",
+                "accountMMRate": "0",
+                "totalEquity": "10262.91335023",
+                "totalWalletBalance": "9684.46297164",
+                "totalMarginBalance": "9684.46297164",
+                "totalAvailableBalance": "9556.6056555",
+                "totalPerpUPL": "0",
+                "totalInitialMargin": "0",
+                "totalMaintenanceMargin": "0",
+                "coin": [{
+                    "coin": "BTC",
+                    "equity": "0.00102964",
+                    "usdValue": "36.70759517",
+                    "walletBalance": "0.00102964",
+                    "availableToWithdraw": "0.00102964",
+                    "availableToBorrow": "",
+                    "borrowAmount": "0",
+                    "accruedInterest": "0",
+                    "totalOrderIM": "",
+                    "totalPositionIM": "",
+                    "totalPositionMM": "",
+                    "unrealisedPnl": "0",
+                    "cumRealisedPnl": "-0.00000973",
+                    "bonus": "0",
+                    "collateralSwitch": true,
+                    "marginCollateral": true,
+                    "locked": "0",
+                    "spotHedgingQty": "0.01592413"
+                }],
+                "accountLTV": "0",
+                "accountType": "UNIFIED"
+            }]
+        }"#;
+
+        match parse_private(serde_json::from_str(json).unwrap())
+            .unwrap()
+            .unwrap()
+        {
+            PrivateMessage::Wallet(accounts) => {
+                assert_eq!(accounts[0].account_type, "UNIFIED");
+                assert_eq!(accounts[0].total_equity_f64().unwrap(), 10262.91335023);
+                let btc = accounts[0].coin_balance("BTC").unwrap();
+                assert_eq!(btc.wallet_balance, "0.00102964");
+                assert_eq!(btc.cum_realised_pnl, "-0.00000973");
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_private_position() {
+        let json = r#"{
+            "id": "1003076014fb7eedb-c7e6-45d6-a8c1-270f0169171a",
+            "topic": "position.linear",
+            "creationTime": 1697682317044,
+            "data": [{
+                "positionIdx": 2,
+                "tradeMode": 0,
+                "riskId": 1,
+                "riskLimitValue": "2000000",
+                "symbol": "BTCUSDT",
+                "side": "",
+                "size": "0",
+                "entryPrice": "0",
+                "leverage": "10",
+                "positionValue": "0",
+                "markPrice": "28184.5",
+                "unrealisedPnl": "0",
+                "positionStatus": "Normal"
+            }]
+        }"#;
+
+        match parse_private(serde_json::from_str(json).unwrap())
+            .unwrap()
+            .unwrap()
+        {
+            PrivateMessage::Position(positions) => {
+                assert_eq!(positions[0].symbol, "BTCUSDT");
+                assert_eq!(positions[0].position_idx, 2);
+                assert_eq!(positions[0].position_status, "Normal");
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_private_execution() {
+        let json = r#"{
+            "topic": "execution",
+            "creationTime": 1672364174455,
+            "data": [{
+                "category": "linear",
+                "symbol": "XRPUSDT",
+                "execFee": "0.005061",
+                "execId": "7e2ae69c-4edf-5800-a352-893d52b446aa",
+                "execPrice": "0.3374",
+                "execQty": "25",
+                "execType": "Trade",
+                "execValue": "8.435",
+                "isMaker": false,
+                "feeRate": "0.0006",
+                "orderId": "f6e324ff-99c2-4e89-9739-3086e47f9381",
+                "orderLinkId": "",
+                "orderPrice": "0.3207",
+                "orderQty": "25",
+                "orderType": "Market",
+                "side": "Sell",
+                "execTime": "1672364174443"
+            }]
+        }"#;
+
+        match parse_private(serde_json::from_str(json).unwrap())
+            .unwrap()
+            .unwrap()
+        {
+            PrivateMessage::Execution(executions) => {
+                assert_eq!(
+                    executions[0].exec_id,
+                    "7e2ae69c-4edf-5800-a352-893d52b446aa"
+                );
+                assert_eq!(executions[0].exec_price, "0.3374");
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_auth_request_frame() {
+        let credentials = Credentials::new("key".to_string(), "test_secret".to_string());
+        let request = auth_request(&credentials, 1662350400000).unwrap();
+
+        assert_eq!(
+            request,
+            serde_json::json!({
+                "op": "auth",
+                "args": [
+                    "key",
+                    1662350400000_i64,
+                    "dad2d67a66c9ee401d2e6e7bf26ebd10eb1026db8a703bf3139c6950ce11a5c6",
+                ],
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_private_auth_rejected() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            let Some(Ok(Message::Text(request))) = socket.next().await else {
+                panic!("expected auth request");
+            };
+            let response =
+                r#"{"success":false,"ret_msg":"Params Error","op":"auth","conn_id":"x"}"#;
+            socket.send(Message::text(response)).await.unwrap();
+            request.to_string()
+        });
+
+        let client = BybitWsClient::new(format!("ws://{}", addr));
+        let credentials = Credentials::new("key".to_string(), "secret".to_string());
+        let result = client.connect_private(credentials).await;
+        assert!(
+            matches!(result, Err(BybitError::AuthenticationError(ref msg)) if msg == "Params Error")
+        );
+
+        let request: serde_json::Value = serde_json::from_str(&server.await.unwrap()).unwrap();
+        assert_eq!(request["op"], "auth");
+        assert_eq!(request["args"][0], "key");
+        let expires = request["args"][1].as_i64().unwrap();
+        assert_eq!(
+            request["args"][2],
+            crate::auth::generate_ws_signature(expires, "secret")
+        );
+    }
+}
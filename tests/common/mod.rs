@@ -0,0 +1,40 @@
+//! Offline harness for integration tests: canned Bybit responses served by a
+//! local mockito server
+
+#![allow(dead_code)]
+
+use rusty_bybit::BybitClient;
+
+/// Body of a successful response wrapping `result`
+pub fn ok_body(result: serde_json::Value) -> String {
+    serde_json::json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": result,
+        "retExtInfo": {},
+        "time": 1672211918471_i64,
+    })
+    .to_string()
+}
+
+/// Body of an API error with `ret_code`, carrying Bybit's empty `result`
+pub fn error_body(ret_code: i32, ret_msg: &str) -> String {
+    serde_json::json!({
+        "retCode": ret_code,
+        "retMsg": ret_msg,
+        "result": {},
+        "retExtInfo": {},
+        "time": 1672211918471_i64,
+    })
+    .to_string()
+}
+
+/// Client without credentials pointed at `server`
+pub fn public_client(server: &mockito::Server) -> BybitClient {
+    BybitClient::new(server.url())
+}
+
+/// Client with dummy credentials pointed at `server`
+pub fn signed_client(server: &mockito::Server) -> BybitClient {
+    public_client(server).with_credentials("test_key".into(), "test_secret".into())
+}
@@ -1,41 +1,128 @@
-use rusty_bybit::BybitClient;
+mod common;
+
+use common::{error_body, ok_body, public_client, signed_client};
+use mockito::Matcher;
+use rusty_bybit::types::{Category, OrderType, Side, TimeInForce};
+use rusty_bybit::{ApiErrorKind, BybitError, CreateOrderRequest};
 
 #[tokio::test]
 async fn test_get_server_time() {
-    let client = BybitClient::testnet();
-    let time = client.get_server_time().await.unwrap();
-    assert!(!time.time_second.is_empty());
-    assert!(!time.time_nano.is_empty());
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v5/market/time")
+        .with_body(ok_body(serde_json::json!({
+            "timeSecond": "1688639403",
+            "timeNano": "1688639403423213947",
+        })))
+        .create_async()
+        .await;
+
+    let time = public_client(&server).get_server_time().await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(time.time_second, "1688639403");
+    assert_eq!(time.time_nano, "1688639403423213947");
 }
 
 #[tokio::test]
 async fn test_get_tickers() {
-    let client = BybitClient::testnet();
-    let tickers = client.get_tickers("linear").await.unwrap();
-    assert!(!tickers.list.is_empty());
-}
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v5/market/tickers")
+        .match_query(Matcher::Exact("category=linear".into()))
+        .with_body(ok_body(serde_json::json!({
+            "category": "linear",
+            "list": [{
+                "symbol": "BTCUSDT",
+                "lastPrice": "16597.00",
+                "indexPrice": "16598.54",
+                "markPrice": "16596.00",
+                "bid1Price": "16596.00",
+                "bid1Size": "1.757",
+                "ask1Price": "16597.50",
+                "ask1Size": "0.159",
+            }],
+        })))
+        .create_async()
+        .await;
 
-#[tokio::test]
-async fn test_get_orderbook() {
-    let client = BybitClient::testnet();
-    let orderbook = client.get_orderbook("linear", "BTCUSDT", 5).await.unwrap();
-    assert!(!orderbook.b.is_empty());
-    assert!(!orderbook.a.is_empty());
+    let tickers = public_client(&server).get_tickers("linear").await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(tickers.list[0].symbol, "BTCUSDT");
+    assert_eq!(tickers.list[0].last_price_f64().unwrap(), 16597.0);
 }
 
 #[tokio::test]
-async fn test_get_instruments() {
-    let client = BybitClient::testnet();
-    let instruments = client.get_instruments("linear").await.unwrap();
-    assert!(!instruments.list.is_empty());
+async fn test_create_order() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/v5/order/create")
+        .match_header("x-bapi-api-key", "test_key")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "category": "linear",
+            "symbol": "BTCUSDT",
+            "side": "Buy",
+            "orderType": "Limit",
+            "qty": "0.001",
+            "price": "28000",
+            "timeInForce": "PostOnly",
+        })))
+        .with_body(ok_body(serde_json::json!({
+            "orderId": "1321003749386327552",
+            "orderLinkId": "spot-test-postonly",
+        })))
+        .create_async()
+        .await;
+
+    let request = CreateOrderRequest::builder()
+        .category(Category::Linear)
+        .symbol("BTCUSDT")
+        .side(Side::Buy)
+        .order_type(OrderType::Limit)
+        .qty("0.001")
+        .price("28000")
+        .time_in_force(TimeInForce::PostOnly)
+        .build()
+        .unwrap();
+    let response = signed_client(&server).create_order(&request).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.order_id, "1321003749386327552");
+    assert_eq!(response.order_link_id, "spot-test-postonly");
 }
 
 #[tokio::test]
-async fn test_get_kline() {
-    let client = BybitClient::testnet();
-    let klines = client
-        .get_kline("linear", "BTCUSDT", "15", None, None)
-        .await
+async fn test_create_order_api_error() {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("POST", "/v5/order/create")
+        .with_body(error_body(110007, "ab not enough for new order"))
+        .create_async()
+        .await;
+
+    let request = CreateOrderRequest::builder()
+        .category(Category::Linear)
+        .symbol("BTCUSDT")
+        .side(Side::Buy)
+        .order_type(OrderType::Market)
+        .qty("100")
+        .build()
         .unwrap();
-    assert!(klines.is_object() || klines.is_array());
+    let error = signed_client(&server)
+        .create_order(&request)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        BybitError::ApiError {
+            ret_code: 110007,
+            ..
+        }
+    ));
+    assert_eq!(
+        error.api_error_kind(),
+        Some(ApiErrorKind::InsufficientBalance)
+    );
 }
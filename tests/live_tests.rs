@@ -0,0 +1,73 @@
+//! Tests against the live Bybit testnet
+//!
+//! Ignored by default so `cargo test` stays offline and deterministic; run
+//! them with `cargo test --test live_tests -- --ignored`.
+
+use rusty_bybit::BybitClient;
+
+#[tokio::test]
+#[ignore = "hits the live testnet"]
+async fn test_get_server_time() {
+    let client = BybitClient::testnet();
+    let time = client.get_server_time().await.unwrap();
+    assert!(!time.time_second.is_empty());
+    assert!(!time.time_nano.is_empty());
+}
+
+#[tokio::test]
+#[ignore = "hits the live testnet"]
+async fn test_get_tickers() {
+    let client = BybitClient::testnet();
+    let tickers = client.get_tickers("linear").await.unwrap();
+    assert!(!tickers.list.is_empty());
+}
+
+#[tokio::test]
+#[ignore = "hits the live testnet"]
+async fn test_get_orderbook() {
+    let client = BybitClient::testnet();
+    let orderbook = client.get_orderbook("linear", "BTCUSDT", 5).await.unwrap();
+    assert!(!orderbook.b.is_empty());
+    assert!(!orderbook.a.is_empty());
+}
+
+#[tokio::test]
+#[ignore = "hits the live testnet"]
+async fn test_get_instruments() {
+    let client = BybitClient::testnet();
+    let instruments = client.get_instruments("linear", None).await.unwrap();
+    assert!(!instruments.list.is_empty());
+}
+
+#[tokio::test]
+#[ignore = "hits the live testnet"]
+async fn test_get_kline() {
+    let client = BybitClient::testnet();
+    let klines = client
+        .get_kline("linear", "BTCUSDT", "15", None, None)
+        .await
+        .unwrap();
+    assert_eq!(klines.symbol, "BTCUSDT");
+    assert!(!klines.list.is_empty());
+}
+
+#[tokio::test]
+#[ignore = "hits the live testnet"]
+async fn test_ws_public_ticker() {
+    use futures_util::StreamExt;
+    use rusty_bybit::ws::{BybitWsClient, PublicMessage};
+
+    let mut stream = BybitWsClient::testnet().connect("linear").await.unwrap();
+    stream.subscribe(&["tickers.BTCUSDT"]).unwrap();
+
+    let message = tokio::time::timeout(std::time::Duration::from_secs(30), stream.next())
+        .await
+        .expect("no push within 30s")
+        .expect("stream closed")
+        .unwrap();
+
+    match message {
+        PublicMessage::Ticker(ticker) => assert_eq!(ticker.symbol, "BTCUSDT"),
+        other => panic!("unexpected message: {:?}", other),
+    }
+}